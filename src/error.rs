@@ -0,0 +1,169 @@
+use crate::version::Increment;
+use std::{env::VarError, error, fmt, num::ParseIntError, str::Utf8Error};
+
+/// An error which can be returned when parsing a [`Version`](crate::Version) or a pattern.
+///
+/// New variants may be added in the future, so matches must include a wildcard arm.
+#[derive(Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ParseError {
+    /// The version is not valid UTF-8.
+    InvalidUtf8(Utf8Error),
+
+    /// The version is empty or only consists of whitespace.
+    EmptyInput,
+
+    /// The version has an empty part, e.g. due to a leading, trailing or doubled dot.
+    EmptyPart(String),
+
+    /// A part of the version could not be parsed as a `u32`.
+    InvalidInteger {
+        /// The part that could not be parsed.
+        part: String,
+
+        /// The error returned by `u32::from_str`.
+        error: ParseIntError,
+    },
+
+    /// A part of the version has a `+` or `-` sign.
+    SignedPart(String),
+
+    /// A part of the version is greater than `u32::MAX`.
+    OutOfRange(String),
+
+    /// The version has more parts than allowed.
+    TooManyParts,
+
+    /// The pattern does not contain a major part.
+    MissingMajor,
+
+    /// The operator of the pattern is not supported.
+    UnknownOperator(String),
+
+    /// The level is not one of `major`, `minor` or `patch`.
+    UnknownLevel(String),
+
+    /// The pattern cannot be expressed as a single range, e.g. because it has a `!=` comparator or
+    /// is a hyphen range whose lower end is above its upper end.
+    NotARange(String),
+
+    /// The pattern requires two distinct exact versions at once, e.g. `=1.2.3, =1.2.4`.
+    Contradiction(String),
+
+    /// The build metadata is empty or has an empty or invalid identifier.
+    InvalidBuild(String),
+
+    /// An environment variable is not set or not valid unicode.
+    MissingEnvVar {
+        /// The name of the variable.
+        name: String,
+
+        /// Whether the variable is not set or not valid unicode.
+        error: VarError,
+    },
+
+    /// An element of a comma-separated list could not be parsed.
+    ListElement {
+        /// The index of the element in the list.
+        index: usize,
+
+        /// The error of the element.
+        error: Box<ParseError>,
+    },
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidUtf8(err) => write!(f, "version is not valid UTF-8: {}", err),
+            Self::EmptyInput => write!(f, "empty version string"),
+            Self::EmptyPart(version) => write!(f, "`{}` has an empty part", version),
+            Self::InvalidInteger { part, .. } => write!(f, "cannot parse `{}` as u32", part),
+            Self::SignedPart(part) => write!(f, "part `{}` must not have a sign", part),
+            Self::OutOfRange(part) => write!(f, "`{}` is out of range for u32", part),
+            Self::TooManyParts => write!(f, "too many parts"),
+            Self::MissingMajor => write!(f, "cannot extract the major part"),
+            Self::UnknownOperator(operator) => write!(f, "operator `{}` not found", operator),
+            Self::UnknownLevel(level) => write!(f, "level `{}` not found", level),
+            Self::NotARange(pattern) => write!(f, "`{}` is not a single range", pattern),
+            Self::Contradiction(pattern) => write!(f, "`{}` can never match", pattern),
+            Self::InvalidBuild(build) => write!(f, "`{}` is not valid build metadata", build),
+            Self::MissingEnvVar { name, error } => match error {
+                VarError::NotPresent => write!(f, "environment variable `{}` is not set", name),
+                VarError::NotUnicode(_) => {
+                    write!(f, "environment variable `{}` is not valid unicode", name)
+                }
+            },
+            Self::ListElement { index, error } => write!(f, "element {}: {}", index, error),
+        }
+    }
+}
+
+// Shows the message rather than the variant so that `unwrap` panics stay readable.
+impl fmt::Debug for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ParseError")
+            .field(&self.to_string())
+            .finish()
+    }
+}
+
+impl error::Error for ParseError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        // Other variants already include their cause in the message, so reporters walking the
+        // chain would print it twice.
+        match self {
+            Self::InvalidInteger { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
+
+/// An error which can be returned when bumping a [`Version`](crate::Version) overflows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OverflowError {
+    /// The level whose part was already at `u32::MAX`.
+    pub level: Increment,
+}
+
+impl fmt::Display for OverflowError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} part is already at u32::MAX", level_name(self.level))
+    }
+}
+
+impl error::Error for OverflowError {}
+
+/// An error which can be returned when creating a [`Version`](crate::Version) with
+/// [`Version::try_new`](crate::Version::try_new).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum InvalidVersionError {
+    /// All parts of the version are zero.
+    Zero,
+
+    /// A part of the version is [`Version::RESERVED`](crate::Version::RESERVED).
+    ReservedPart {
+        /// The level of the first reserved part.
+        level: Increment,
+    },
+}
+
+impl fmt::Display for InvalidVersionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Zero => write!(f, "version cannot be `0.0.0`"),
+            Self::ReservedPart { level } => write!(f, "{} part is reserved", level_name(*level)),
+        }
+    }
+}
+
+impl error::Error for InvalidVersionError {}
+
+fn level_name(level: Increment) -> &'static str {
+    match level {
+        Increment::Major => "major",
+        Increment::Minor => "minor",
+        Increment::Patch => "patch",
+    }
+}
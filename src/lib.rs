@@ -27,5 +27,12 @@
 //! change convey meaning about the underlying code and what has been modified from one version to
 //! the next.
 
+pub mod error;
+pub mod partial;
+pub mod range;
+pub mod req;
 pub mod version;
+pub use crate::error::ParseError;
+pub use crate::range::Range;
+pub use crate::req::VersionReq;
 pub use crate::version::Version;
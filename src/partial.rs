@@ -0,0 +1,185 @@
+use crate::{version, ParseError, Range, Version};
+use std::{ops::Bound, result, str::FromStr};
+
+type Result<T> = result::Result<T, ParseError>;
+
+/// A part of a [`PartialVersion`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    /// Matches exactly the given number.
+    Number(u32),
+
+    /// Matches any number, written as `x`, `X` or `*`.
+    Any,
+}
+
+impl FromStr for Part {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> result::Result<Self, Self::Err> {
+        match s {
+            "x" | "X" | "*" => Ok(Self::Any),
+            _ => Ok(Self::Number(version::parse_part(s)?)),
+        }
+    }
+}
+
+/// A version pattern whose parts may be wildcards, e.g. `1.2.x`.
+///
+/// Unlike [`Version`], this describes a set of versions rather than a concrete one. Missing parts
+/// are treated as wildcards and every part after the first wildcard is ignored.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartialVersion {
+    /// Represents incompatible API changes.
+    pub major: Part,
+
+    /// Represents functionality additions in a backwards compatible manner.
+    pub minor: Part,
+
+    /// Represents bug fixes in a backwards compatible manner.
+    pub patch: Part,
+}
+
+impl PartialVersion {
+    /// Converts instance of [`PartialVersion`] into the [`Range`] of versions it matches.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use samurai::{partial::PartialVersion, Version};
+    ///
+    /// let partial = "1.2.x".parse::<PartialVersion>().expect("`1.2.x` should be a valid pattern");
+    /// let range = partial.to_range();
+    ///
+    /// assert!(range.contains(&Version::new(1, 2, 9)));
+    /// assert!(!range.contains(&Version::new(1, 3, 0)));
+    /// ```
+    pub fn to_range(&self) -> Range {
+        match (self.major, self.minor, self.patch) {
+            (Part::Any, _, _) => Range::new(Bound::Unbounded, Bound::Unbounded),
+            (Part::Number(major), Part::Any, _) => Self::half_open(
+                Version::new(major, 0, 0),
+                major.checked_add(1).map(|major| Version::new(major, 0, 0)),
+            ),
+            (Part::Number(major), Part::Number(minor), Part::Any) => Self::half_open(
+                Version::new(major, minor, 0),
+                minor
+                    .checked_add(1)
+                    .map(|minor| Version::new(major, minor, 0)),
+            ),
+            (Part::Number(major), Part::Number(minor), Part::Number(patch)) => {
+                let version = Version::new(major, minor, patch);
+                Range::new(Bound::Included(version.clone()), Bound::Included(version))
+            }
+        }
+    }
+
+    /// Creates `lower..upper`, leaving the upper bound open if it would overflow.
+    fn half_open(lower: Version, upper: Option<Version>) -> Range {
+        Range::new(
+            Bound::Included(lower),
+            upper.map_or(Bound::Unbounded, Bound::Excluded),
+        )
+    }
+}
+
+impl FromStr for PartialVersion {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> result::Result<Self, Self::Err> {
+        let s = s.trim_matches(|ch: char| ch.is_ascii_whitespace());
+        if s.is_empty() {
+            return Err(ParseError::EmptyInput);
+        }
+
+        let parts = s
+            .split('.')
+            .map(|part| {
+                if part.is_empty() {
+                    return Err(ParseError::EmptyPart(s.to_string()));
+                }
+
+                part.parse()
+            })
+            .collect::<Result<Vec<Part>>>()?;
+
+        if parts.len() > 3 {
+            return Err(ParseError::TooManyParts);
+        }
+
+        Ok(Self {
+            major: parts[0],
+            minor: parts.get(1).copied().unwrap_or(Part::Any),
+            patch: parts.get(2).copied().unwrap_or(Part::Any),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_wildcards() -> Result<()> {
+        for s in ["1.2.x", "1.2.X", "1.2.*", "1.2"] {
+            let partial = s.parse::<PartialVersion>()?;
+
+            assert_eq!(partial.major, Part::Number(1));
+            assert_eq!(partial.minor, Part::Number(2));
+            assert_eq!(partial.patch, Part::Any);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    #[should_panic(expected = "as u32")]
+    fn from_invalid_part_panics() {
+        "1.y.0".parse::<PartialVersion>().unwrap();
+    }
+
+    #[test]
+    fn minor_wildcard_to_range() -> Result<()> {
+        let range = "1.2.x".parse::<PartialVersion>()?.to_range();
+
+        assert_eq!(range.lower, Bound::Included(Version::new(1, 2, 0)));
+        assert_eq!(range.upper, Bound::Excluded(Version::new(1, 3, 0)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn major_wildcard_to_range() -> Result<()> {
+        let range = "1.x".parse::<PartialVersion>()?.to_range();
+
+        assert_eq!(range.lower, Bound::Included(Version::new(1, 0, 0)));
+        assert_eq!(range.upper, Bound::Excluded(Version::new(2, 0, 0)));
+
+        let range = "*".parse::<PartialVersion>()?.to_range();
+
+        assert_eq!(range.lower, Bound::Unbounded);
+        assert_eq!(range.upper, Bound::Unbounded);
+
+        Ok(())
+    }
+
+    #[test]
+    fn full_to_range() -> Result<()> {
+        let range = "1.2.3".parse::<PartialVersion>()?.to_range();
+
+        assert_eq!(range.lower, Bound::Included(Version::new(1, 2, 3)));
+        assert_eq!(range.upper, Bound::Included(Version::new(1, 2, 3)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn wildcard_at_u32_max_to_range() -> Result<()> {
+        let range = "4294967295.x".parse::<PartialVersion>()?.to_range();
+
+        assert_eq!(range.lower, Bound::Included(Version::new(u32::MAX, 0, 0)));
+        assert_eq!(range.upper, Bound::Unbounded);
+
+        Ok(())
+    }
+}
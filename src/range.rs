@@ -0,0 +1,289 @@
+use crate::{partial::PartialVersion, req, ParseError, Version};
+use std::{
+    ops::{Bound, RangeBounds},
+    result,
+    str::FromStr,
+};
+
+type Result<T> = result::Result<T, ParseError>;
+
+/// A range of [`Version`]s between a lower and an upper bound.
+///
+/// A range can be parsed from a hyphen range (e.g. `1.2.3 - 2.0.0`, both ends inclusive, while a
+/// partial upper end like the `2` of `1.2 - 2` covers everything it matches) or from a pattern
+/// (e.g. `>=1.2, <2.0`), in which case the open side of a single comparator like `>=1.2` is
+/// unbounded.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Range {
+    /// The lower bound of the range.
+    pub lower: Bound<Version>,
+
+    /// The upper bound of the range.
+    pub upper: Bound<Version>,
+}
+
+impl Range {
+    /// Creates a new [`Range`].
+    pub fn new(lower: Bound<Version>, upper: Bound<Version>) -> Self {
+        Self { lower, upper }
+    }
+
+    /// Creates a [`Range`] of versions compatible with `version`, i.e. `^version`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use samurai::{Range, Version};
+    ///
+    /// let range = Range::caret(&Version::new(1, 2, 9));
+    ///
+    /// assert!(range.contains(&Version::new(1, 5, 7)));
+    /// assert!(!range.contains(&Version::new(2, 0, 0)));
+    /// ```
+    pub fn caret(version: &Version) -> Self {
+        Self::new(Bound::Included(version.clone()), caret_upper(version))
+    }
+
+    /// Creates a [`Range`] of versions featureless since `version`, i.e. `~version`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use samurai::{Range, Version};
+    ///
+    /// let range = Range::tilde(&Version::new(1, 5, 4));
+    ///
+    /// assert!(range.contains(&Version::new(1, 5, 7)));
+    /// assert!(!range.contains(&Version::new(1, 6, 2)));
+    /// ```
+    pub fn tilde(version: &Version) -> Self {
+        Self::new(Bound::Included(version.clone()), tilde_upper(version))
+    }
+
+    /// Checks whether `version` falls in instance of [`Range`].
+    pub fn contains(&self, version: &Version) -> bool {
+        let above_lower = match &self.lower {
+            Bound::Included(lower) => version >= lower,
+            Bound::Excluded(lower) => version > lower,
+            Bound::Unbounded => true,
+        };
+
+        let below_upper = match &self.upper {
+            Bound::Included(upper) => version <= upper,
+            Bound::Excluded(upper) => version < upper,
+            Bound::Unbounded => true,
+        };
+
+        above_lower && below_upper
+    }
+}
+
+/// Returns the upper bound of `^version`, which is unbounded if the part to increment is already
+/// `u32::MAX`.
+pub(crate) fn caret_upper(version: &Version) -> Bound<Version> {
+    let upper = if version.major == 0 {
+        version.checked_bump_minor()
+    } else {
+        version.checked_bump_major()
+    };
+
+    upper.map_or(Bound::Unbounded, Bound::Excluded)
+}
+
+/// Returns the upper bound of `~version`, which is unbounded if the minor part is already
+/// `u32::MAX`.
+pub(crate) fn tilde_upper(version: &Version) -> Bound<Version> {
+    version
+        .checked_bump_minor()
+        .map_or(Bound::Unbounded, Bound::Excluded)
+}
+
+impl RangeBounds<Version> for Range {
+    fn start_bound(&self) -> Bound<&Version> {
+        self.lower.as_ref()
+    }
+
+    fn end_bound(&self) -> Bound<&Version> {
+        self.upper.as_ref()
+    }
+}
+
+impl FromStr for Range {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        if let Some((lower, upper)) = s.split_once(" - ") {
+            let lower = lower.parse::<Version>()?;
+            let upper = upper.parse::<PartialVersion>()?.to_range().upper;
+
+            let is_reversed = match &upper {
+                Bound::Included(upper) => lower > *upper,
+                Bound::Excluded(upper) => lower >= *upper,
+                Bound::Unbounded => false,
+            };
+
+            if is_reversed {
+                return Err(ParseError::NotARange(s.to_string()));
+            }
+
+            return Ok(Self::new(Bound::Included(lower), upper));
+        }
+
+        let (lower, upper) = req::bounds(s)?;
+
+        Ok(Self::new(lower, upper))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn caret_contains() {
+        let range = Range::caret(&Version::new(8, 9, 1));
+
+        assert!(range.contains(&Version::new(8, 9, 1)));
+        assert!(range.contains(&Version::new(8, 10, 5)));
+        assert!(!range.contains(&Version::new(8, 9, 0)));
+        assert!(!range.contains(&Version::new(9, 0, 0)));
+    }
+
+    #[test]
+    fn caret_contains_on_beta() {
+        let range = Range::caret(&Version::new(0, 10, 5));
+
+        assert!(range.contains(&Version::new(0, 10, 6)));
+        assert!(!range.contains(&Version::new(0, 11, 0)));
+    }
+
+    #[test]
+    fn tilde_contains() {
+        let range = Range::tilde(&Version::new(30, 11, 20));
+
+        assert!(range.contains(&Version::new(30, 11, 21)));
+        assert!(!range.contains(&Version::new(30, 12, 0)));
+    }
+
+    #[test]
+    fn caret_and_tilde_at_u32_max() {
+        let range = Range::caret(&Version::MAX);
+        assert_eq!(range.upper, Bound::Unbounded);
+        assert!(range.contains(&Version::MAX));
+
+        let range = Range::tilde(&Version::new(1, u32::MAX, 0));
+        assert_eq!(range.upper, Bound::Unbounded);
+        assert!(range.contains(&Version::new(1, u32::MAX, 7)));
+    }
+
+    #[test]
+    fn unbounded_contains() {
+        let range = Range::new(Bound::Excluded(Version::new(1, 0, 0)), Bound::Unbounded);
+
+        assert!(range.contains(&Version::new(1, 0, 1)));
+        assert!(range.contains(&Version::new(u32::MAX, 0, 0)));
+        assert!(!range.contains(&Version::new(1, 0, 0)));
+    }
+
+    #[test]
+    fn parse_hyphen_range() -> Result<()> {
+        let range = "1.2.3 - 2.0.0".parse::<Range>()?;
+
+        assert_eq!(
+            range,
+            Range::new(
+                Bound::Included(Version::new(1, 2, 3)),
+                Bound::Included(Version::new(2, 0, 0)),
+            )
+        );
+        assert_eq!(range, ">=1.2.3, <=2.0.0".parse()?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn parse_partial_hyphen_range() -> Result<()> {
+        let range = "1.2 - 2".parse::<Range>()?;
+
+        assert_eq!(
+            range,
+            Range::new(
+                Bound::Included(Version::new(1, 2, 0)),
+                Bound::Excluded(Version::new(3, 0, 0)),
+            )
+        );
+        assert!(range.contains(&Version::new(2, 9, 9)));
+
+        let range = "1.2.3 - 2.4".parse::<Range>()?;
+        assert_eq!(range.upper, Bound::Excluded(Version::new(2, 5, 0)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn parse_reversed_hyphen_range_errors() -> Result<()> {
+        for pattern in ["2.0.0 - 1.0.0", "2.0.0 - 1"] {
+            assert_eq!(
+                pattern.parse::<Range>(),
+                Err(ParseError::NotARange(pattern.to_string()))
+            );
+        }
+
+        let range = "1.0.0 - 1.0.0".parse::<Range>()?;
+        assert!(range.contains(&Version::new(1, 0, 0)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn parse_paired_comparators() -> Result<()> {
+        let range = ">=1.2, <2.0".parse::<Range>()?;
+
+        assert_eq!(
+            range,
+            Range::new(
+                Bound::Included(Version::new(1, 2, 0)),
+                Bound::Excluded(Version::new(2, 0, 0)),
+            )
+        );
+        assert_eq!(range, "^1.2".parse()?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn parse_single_comparator() -> Result<()> {
+        let range = ">1.0.0".parse::<Range>()?;
+
+        assert_eq!(
+            range,
+            Range::new(Bound::Excluded(Version::new(1, 0, 0)), Bound::Unbounded)
+        );
+        assert_eq!(
+            "*".parse::<Range>()?,
+            Range::new(Bound::Unbounded, Bound::Unbounded)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    #[should_panic(expected = "is not a single range")]
+    fn parse_not_equal_panics() {
+        ">=1.0.0, !=1.2.0".parse::<Range>().unwrap();
+    }
+
+    #[test]
+    fn range_bounds_contains() {
+        fn contains_through_trait<R: RangeBounds<Version>>(range: &R, version: &Version) -> bool {
+            range.contains(version)
+        }
+
+        let range = Range::caret(&Version::new(1, 2, 0));
+
+        assert_eq!(range.start_bound(), Bound::Included(&Version::new(1, 2, 0)));
+        assert_eq!(range.end_bound(), Bound::Excluded(&Version::new(2, 0, 0)));
+        assert!(contains_through_trait(&range, &Version::new(1, 9, 9)));
+        assert!(!contains_through_trait(&range, &Version::new(2, 0, 0)));
+    }
+}
@@ -0,0 +1,1546 @@
+use crate::{range, version::Precision, ParseError, Version};
+use std::{cmp::Ordering, fmt, ops::Bound, result, str::FromStr};
+
+type Result<T> = result::Result<T, ParseError>;
+
+/// An operator of a pattern, e.g. the `>=` of `>=1.2.0`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Operator {
+    /// `=`, matches exactly the version.
+    Eq,
+
+    /// `!=`, matches anything but the version.
+    Neq,
+
+    /// `<`, matches versions less than the version.
+    Lt,
+
+    /// `>`, matches versions greater than the version.
+    Gt,
+
+    /// `<=`, matches versions less than or equal to the version.
+    Lte,
+
+    /// `>=`, matches versions greater than or equal to the version.
+    Gte,
+
+    /// `^`, matches versions compatible with the version.
+    Caret,
+
+    /// `~`, matches versions featureless since the version.
+    Tilde,
+}
+
+impl fmt::Display for Operator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let symbol = match self {
+            Self::Eq => "=",
+            Self::Neq => "!=",
+            Self::Lt => "<",
+            Self::Gt => ">",
+            Self::Lte => "<=",
+            Self::Gte => ">=",
+            Self::Caret => "^",
+            Self::Tilde => "~",
+        };
+
+        write!(f, "{}", symbol)
+    }
+}
+
+impl FromStr for Operator {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> result::Result<Self, Self::Err> {
+        match s {
+            "=" => Ok(Self::Eq),
+            "!=" => Ok(Self::Neq),
+            "<" => Ok(Self::Lt),
+            ">" => Ok(Self::Gt),
+            "<=" => Ok(Self::Lte),
+            ">=" => Ok(Self::Gte),
+            "^" => Ok(Self::Caret),
+            "~" => Ok(Self::Tilde),
+            _ => Err(ParseError::UnknownOperator(s.to_string())),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Comparator {
+    operator: Operator,
+    version: Version,
+}
+
+impl Comparator {
+    fn matches(&self, version: &Version) -> bool {
+        let other = &self.version;
+
+        match self.operator {
+            Operator::Eq => version == other,
+            Operator::Neq => version != other,
+            Operator::Lt => version < other,
+            Operator::Gt => version > other,
+            Operator::Lte => version <= other,
+            Operator::Gte => version >= other,
+            Operator::Caret => version.is_compatible(other),
+            Operator::Tilde => version.is_featureless(other),
+        }
+    }
+
+    fn describe(&self) -> String {
+        let version = &self.version;
+
+        match self.operator {
+            Operator::Eq => format!("exactly {}", version),
+            Operator::Neq => format!("any version except {}", version),
+            Operator::Lt => format!("less than {}", version),
+            Operator::Gt => format!("greater than {}", version),
+            Operator::Lte => format!("at most {}", version),
+            Operator::Gte => format!("at least {}", version),
+            Operator::Caret | Operator::Tilde => {
+                let kind = match self.operator {
+                    Operator::Caret => "compatible with",
+                    _ => "approximately",
+                };
+
+                match self.upper_bound() {
+                    Bound::Excluded(upper) => {
+                        format!("{} {} (>={} and <{})", kind, version, version, upper)
+                    }
+                    _ => format!("{} {} (>={}, unbounded above)", kind, version, version),
+                }
+            }
+        }
+    }
+
+    fn explain(&self, version: &Version) -> result::Result<(), String> {
+        if self.matches(version) {
+            return Ok(());
+        }
+
+        let other = &self.version;
+
+        Err(match self.operator {
+            Operator::Eq => format!("{} is not {}", version, other),
+            Operator::Neq => format!("{} is excluded", version),
+            Operator::Lt => format!("{} is not below the upper bound {}", version, other),
+            Operator::Lte => format!("{} is above the upper bound {}", version, other),
+            Operator::Gt => format!("{} is not above the lower bound {}", version, other),
+            Operator::Gte => format!("{} is below the lower bound {}", version, other),
+            Operator::Caret | Operator::Tilde => match self.upper_bound() {
+                Bound::Excluded(upper) if version >= other => {
+                    format!("{} is not below the upper bound {}", version, upper)
+                }
+                _ => format!("{} is below the lower bound {}", version, other),
+            },
+        })
+    }
+
+    /// Returns the lower and upper bound of the comparator, or [`None`] for `!=`.
+    fn bounds(&self) -> Option<(Bound<Version>, Bound<Version>)> {
+        let version = self.version.clone();
+
+        Some(match self.operator {
+            Operator::Eq => (Bound::Included(version.clone()), Bound::Included(version)),
+            Operator::Neq => return None,
+            Operator::Lt => (Bound::Unbounded, Bound::Excluded(version)),
+            Operator::Gt => (Bound::Excluded(version), Bound::Unbounded),
+            Operator::Lte => (Bound::Unbounded, Bound::Included(version)),
+            Operator::Gte => (Bound::Included(version), Bound::Unbounded),
+            Operator::Caret | Operator::Tilde => (Bound::Included(version), self.upper_bound()),
+        })
+    }
+
+    /// Returns the upper bound of caret and tilde comparators, which is unbounded if the part to
+    /// increment is already `u32::MAX`.
+    fn upper_bound(&self) -> Bound<Version> {
+        match self.operator {
+            Operator::Caret => range::caret_upper(&self.version),
+            _ => range::tilde_upper(&self.version),
+        }
+    }
+}
+
+impl fmt::Display for Comparator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", self.operator, self.version)
+    }
+}
+
+impl FromStr for Comparator {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> result::Result<Self, Self::Err> {
+        let Some(version_start) = s.find(|ch: char| ch.is_numeric()) else {
+            return Err(ParseError::MissingMajor);
+        };
+
+        let operator = s[..version_start].trim_end_matches(|ch: char| ch.is_ascii_whitespace());
+        let operator = match operator {
+            "" => Operator::Caret,
+            operator => operator.parse()?,
+        };
+
+        Ok(Self {
+            operator,
+            version: s[version_start..].parse()?,
+        })
+    }
+}
+
+/// A parsed pattern that can be matched against many [`Version`]s without parsing it again.
+///
+/// You can find a cheat-sheet of patterns [here](https://devhints.io/semver). A pattern without an
+/// operator (e.g. `1.2.9`) is treated as a caret requirement, just like npm and Cargo do, while
+/// `*` or an empty pattern matches any version. A partial version without an operator matches
+/// any value of its missing parts instead, e.g. `1.2` means `>=1.2.0, <1.3.0`.
+///
+/// Several comparators can be combined by separating them with commas and/or whitespace (e.g.
+/// `>=1.2.0, <2.0.0`), in which case a version has to satisfy all of them. Such sets of
+/// comparators can in turn be separated with `||` (e.g. `^1.0.0 || ^2.0.0`), in which case a
+/// version has to satisfy any of them. An operator may be separated from its version by
+/// whitespace, e.g. `>= 1.2.0`.
+///
+/// The keyword `latest` matches any version on its own, use [`VersionReq::resolve_keyword`] to
+/// pick the version it refers to from a pool.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VersionReq {
+    /// The alternative sets of comparators, a wildcard is a single empty set.
+    sets: Vec<Vec<Comparator>>,
+    keyword: Option<Keyword>,
+}
+
+/// A pseudo-requirement that can only be resolved against a pool of versions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Keyword {
+    Latest,
+}
+
+impl VersionReq {
+    pub(crate) fn exact(version: Version) -> Self {
+        Self {
+            sets: vec![vec![Comparator {
+                operator: Operator::Eq,
+                version,
+            }]],
+            keyword: None,
+        }
+    }
+
+    /// Returns the tightest lower and upper bound of instance of [`VersionReq`], or [`None`] if it
+    /// has alternatives or a `!=` comparator and therefore cannot be expressed as a single range.
+    fn bounds(&self) -> Option<(Bound<Version>, Bound<Version>)> {
+        let [comparators] = self.sets.as_slice() else {
+            return None;
+        };
+
+        if comparators
+            .iter()
+            .any(|comparator| comparator.operator == Operator::Neq)
+        {
+            return None;
+        }
+
+        Some(merge_bounds(comparators))
+    }
+
+    /// Resolves the `latest` keyword to the greatest version of `pool`.
+    ///
+    /// Returns [`None`] if instance of [`VersionReq`] is not a keyword or `pool` is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use samurai::{Version, VersionReq};
+    ///
+    /// let pool = [Version::new(1, 2, 0), Version::new(2, 0, 0), Version::new(1, 9, 0)];
+    /// let req = "latest".parse::<VersionReq>().expect("`latest` should be a valid pattern");
+    ///
+    /// assert_eq!(req.resolve_keyword(&pool), Some(Version::new(2, 0, 0)));
+    /// ```
+    pub fn resolve_keyword(&self, pool: &[Version]) -> Option<Version> {
+        match self.keyword? {
+            Keyword::Latest => pool.iter().max().cloned(),
+        }
+    }
+
+    /// Checks whether instance of [`VersionReq`] only matches a single [`Version`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use samurai::VersionReq;
+    ///
+    /// let exact = "=1.2.9".parse::<VersionReq>().expect("`=1.2.9` should be a valid pattern");
+    /// let caret = "^1.2.9".parse::<VersionReq>().expect("`^1.2.9` should be a valid pattern");
+    ///
+    /// assert!(exact.is_exact());
+    /// assert!(!caret.is_exact());
+    /// ```
+    pub fn is_exact(&self) -> bool {
+        match self.sets.as_slice() {
+            [comparators] => matches!(
+                comparators.as_slice(),
+                [Comparator {
+                    operator: Operator::Eq,
+                    ..
+                }]
+            ),
+            _ => false,
+        }
+    }
+
+    /// Checks whether `version` satisfies instance of [`VersionReq`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use samurai::{Version, VersionReq};
+    ///
+    /// let req = "^1.2.9".parse::<VersionReq>().expect("`^1.2.9` should be a valid pattern");
+    ///
+    /// assert!(req.matches(&Version::new(1, 5, 7)));
+    /// assert!(!req.matches(&Version::new(2, 0, 0)));
+    /// ```
+    pub fn matches(&self, version: &Version) -> bool {
+        self.sets.iter().any(|comparators| {
+            comparators
+                .iter()
+                .all(|comparator| comparator.matches(version))
+        })
+    }
+
+    /// Explains why `version` does not satisfy instance of [`VersionReq`].
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error describing the first unsatisfied comparator, of every
+    /// alternative if there are several.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use samurai::{Version, VersionReq};
+    ///
+    /// let req = "^2.0.0".parse::<VersionReq>().expect("`^2.0.0` should be a valid pattern");
+    ///
+    /// assert_eq!(
+    ///     req.explain(&Version::new(1, 2, 3)),
+    ///     Err("1.2.3 is below the lower bound 2.0.0".to_string())
+    /// );
+    /// ```
+    pub fn explain(&self, version: &Version) -> result::Result<(), String> {
+        let mut reasons = Vec::new();
+
+        for comparators in &self.sets {
+            match comparators
+                .iter()
+                .try_for_each(|comparator| comparator.explain(version))
+            {
+                Ok(()) => return Ok(()),
+                Err(reason) => reasons.push(reason),
+            }
+        }
+
+        Err(reasons.join("; "))
+    }
+
+    /// Describes instance of [`VersionReq`] in plain English.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use samurai::VersionReq;
+    ///
+    /// let req = "^1.2.3".parse::<VersionReq>().expect("`^1.2.3` should be a valid pattern");
+    ///
+    /// assert_eq!(req.describe(), "compatible with 1.2.3 (>=1.2.3 and <2.0.0)");
+    /// ```
+    pub fn describe(&self) -> String {
+        if let Some(Keyword::Latest) = self.keyword {
+            return "the latest version".to_string();
+        }
+
+        self.sets
+            .iter()
+            .map(|comparators| {
+                if comparators.is_empty() {
+                    return "any version".to_string();
+                }
+
+                comparators
+                    .iter()
+                    .map(Comparator::describe)
+                    .collect::<Vec<_>>()
+                    .join(" and ")
+            })
+            .collect::<Vec<_>>()
+            .join(" or ")
+    }
+}
+
+/// Renders the canonical form of the pattern, which parses back into an equal [`VersionReq`].
+///
+/// Comparators are sorted by version and then by operator and separated by `, `, versions are
+/// written with all three parts and caret or tilde comparators are kept as they are rather than
+/// expanded, e.g. `>=1.2` and `^1.2` render as `>=1.2.0` and `^1.2.0`. A set of comparators that
+/// matches any version renders as `*` and alternatives are separated by ` || `.
+impl fmt::Display for VersionReq {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(Keyword::Latest) = self.keyword {
+            return write!(f, "latest");
+        }
+
+        for (index, comparators) in self.sets.iter().enumerate() {
+            if index > 0 {
+                write!(f, " || ")?;
+            }
+
+            if comparators.is_empty() {
+                write!(f, "*")?;
+                continue;
+            }
+
+            let mut comparators = comparators.iter().collect::<Vec<_>>();
+            comparators.sort_by(|a, b| a.version.cmp(&b.version).then(a.operator.cmp(&b.operator)));
+
+            for (index, comparator) in comparators.into_iter().enumerate() {
+                if index > 0 {
+                    write!(f, ", ")?;
+                }
+
+                write!(f, "{}", comparator)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for VersionReq {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> result::Result<Self, Self::Err> {
+        let keyword = match s.trim_matches(|ch: char| ch.is_ascii_whitespace()) {
+            "*" => None,
+            "latest" => Some(Keyword::Latest),
+            _ => {
+                let sets = s
+                    .split("||")
+                    .map(|set| {
+                        if set.trim_matches(|ch: char| ch.is_ascii_whitespace()) == "*" {
+                            return Ok(Vec::new());
+                        }
+
+                        parse_set(set)
+                    })
+                    .collect::<Result<_>>()?;
+
+                return Ok(Self {
+                    sets,
+                    keyword: None,
+                });
+            }
+        };
+
+        Ok(Self {
+            sets: vec![Vec::new()],
+            keyword,
+        })
+    }
+}
+
+/// Parses a set of comparators separated by commas and/or whitespace, e.g. `>=1.2.0, <2.0.0`.
+fn parse_set(set: &str) -> Result<Vec<Comparator>> {
+    let mut tokens = set
+        .split(|ch: char| ch == ',' || ch.is_ascii_whitespace())
+        .filter(|token| !token.is_empty())
+        .peekable();
+
+    let mut comparators = Vec::new();
+    while let Some(token) = tokens.next() {
+        // An operator may be separated from its version by whitespace, e.g. `>= 1.2.0`.
+        let comparator = match tokens.peek() {
+            Some(version) if !token.contains(|ch: char| ch.is_numeric()) => {
+                let comparator = format!("{}{}", token, version);
+                tokens.next();
+                comparator
+            }
+            _ => token.to_string(),
+        };
+
+        comparators.extend(parse_comparators(&comparator)?);
+    }
+
+    let mut exact = comparators
+        .iter()
+        .filter(|comparator| comparator.operator == Operator::Eq);
+
+    if let Some(first) = exact.next() {
+        if exact.any(|comparator| comparator.version != first.version) {
+            return Err(ParseError::Contradiction(
+                set.trim_matches(|ch: char| ch.is_ascii_whitespace())
+                    .to_string(),
+            ));
+        }
+    }
+
+    Ok(comparators)
+}
+
+/// Parses a single comparator, expanding it into the range of the missing parts of its version
+/// where needed, e.g. `1`, `^1` and `~1` into `>=1.0.0, <2.0.0` and `1.2` into `>=1.2.0, <1.3.0`.
+fn parse_comparators(s: &str) -> Result<Vec<Comparator>> {
+    let comparator = s.parse::<Comparator>()?;
+    let is_bare = s.starts_with(|ch: char| ch.is_numeric());
+    let version = &comparator.version;
+
+    let upper = match (comparator.operator, version.precision()) {
+        (Operator::Caret | Operator::Tilde, Precision::Major) => version.checked_bump_major(),
+        (Operator::Caret, Precision::Minor) if is_bare => version.checked_bump_minor(),
+        _ => return Ok(vec![comparator]),
+    };
+
+    let lower = Comparator {
+        operator: Operator::Gte,
+        version: comparator.version,
+    };
+
+    Ok(match upper {
+        Some(upper) => vec![
+            lower,
+            Comparator {
+                operator: Operator::Lt,
+                version: upper,
+            },
+        ],
+        None => vec![lower],
+    })
+}
+
+/// Returns a [`VersionReq`] satisfied by exactly the versions that satisfy both `a` and `b`, or
+/// [`None`] if no version can satisfy both.
+///
+/// The bounds of each pair of alternatives are merged into at most one lower and one upper bound,
+/// `!=` comparators are carried over as they are. A keyword like `latest` only matters when
+/// resolving against a pool, so it is dropped and the result is a plain requirement.
+///
+/// # Examples
+///
+/// ```
+/// use samurai::{req, VersionReq};
+///
+/// let a = "^1.2".parse::<VersionReq>().expect("`^1.2` should be a valid pattern");
+/// let b = ">=1.5".parse::<VersionReq>().expect("`>=1.5` should be a valid pattern");
+/// let c = "<1.0".parse::<VersionReq>().expect("`<1.0` should be a valid pattern");
+///
+/// let both = req::intersect(&a, &b).expect("`^1.2` and `>=1.5` should overlap");
+///
+/// assert_eq!(both.describe(), "at least 1.5.0 and less than 2.0.0");
+/// assert!(req::intersect(&a, &c).is_none());
+/// ```
+pub fn intersect(a: &VersionReq, b: &VersionReq) -> Option<VersionReq> {
+    let sets = a
+        .sets
+        .iter()
+        .flat_map(|a| b.sets.iter().map(move |b| (a, b)))
+        .filter_map(|(a, b)| intersect_sets(a, b))
+        .collect::<Vec<_>>();
+
+    if sets.is_empty() {
+        return None;
+    }
+
+    Some(VersionReq {
+        sets,
+        keyword: None,
+    })
+}
+
+/// Returns the comparators satisfied by exactly the versions that satisfy both `a` and `b`, or
+/// [`None`] if no version can satisfy both.
+fn intersect_sets(a: &[Comparator], b: &[Comparator]) -> Option<Vec<Comparator>> {
+    let comparators = || a.iter().chain(b);
+    let (lower, upper) = merge_bounds(comparators());
+    let excluded = comparators()
+        .filter(|comparator| comparator.operator == Operator::Neq)
+        .cloned()
+        .collect::<Vec<_>>();
+
+    let bounded = |operator, version: &Version| Comparator {
+        operator,
+        version: version.clone(),
+    };
+
+    let mut comparators = match (&lower, &upper) {
+        (Bound::Included(lower), Bound::Included(upper)) if lower == upper => {
+            if excluded
+                .iter()
+                .any(|comparator| &comparator.version == lower)
+            {
+                return None;
+            }
+
+            vec![bounded(Operator::Eq, lower)]
+        }
+        _ => {
+            // Walk up from the least version in the range past the excluded ones, which takes at
+            // most one step per `!=` comparator.
+            let mut least = match &lower {
+                Bound::Included(lower) => Some(lower.clone()),
+                Bound::Excluded(lower) => successor(lower),
+                Bound::Unbounded => Some(Version::MIN),
+            };
+
+            while let Some(version) = least.take_if(|version| {
+                excluded
+                    .iter()
+                    .any(|comparator| comparator.version == *version)
+            }) {
+                least = successor(&version);
+            }
+
+            let is_empty = match (&least, &upper) {
+                (None, _) => true,
+                (Some(least), Bound::Included(upper)) => least > upper,
+                (Some(least), Bound::Excluded(upper)) => least >= upper,
+                (Some(_), Bound::Unbounded) => false,
+            };
+
+            if is_empty {
+                return None;
+            }
+
+            let lower = match &lower {
+                Bound::Included(lower) => Some(bounded(Operator::Gte, lower)),
+                Bound::Excluded(lower) => Some(bounded(Operator::Gt, lower)),
+                Bound::Unbounded => None,
+            };
+
+            let upper = match &upper {
+                Bound::Included(upper) => Some(bounded(Operator::Lte, upper)),
+                Bound::Excluded(upper) => Some(bounded(Operator::Lt, upper)),
+                Bound::Unbounded => None,
+            };
+
+            lower.into_iter().chain(upper).collect()
+        }
+    };
+
+    comparators.extend(excluded);
+
+    Some(comparators)
+}
+
+/// Returns the least version greater than `version`, or [`None`] if `version` is the greatest.
+fn successor(version: &Version) -> Option<Version> {
+    version
+        .checked_bump_patch()
+        .or_else(|| version.checked_bump_minor())
+        .or_else(|| version.checked_bump_major())
+}
+
+/// Expands `req` into its explicit lower and upper bound.
+///
+/// Caret, tilde and partial requirements are expanded into the versions they span, e.g. `^1.2.3`
+/// into `1.2.3` inclusive and `2.0.0` exclusive, while an open side is [`Bound::Unbounded`].
+///
+/// # Errors
+///
+/// This function will return an error if it cannot detect a valid pattern, or if the pattern has
+/// alternatives or a `!=` comparator and therefore cannot be expressed as a single range.
+///
+/// # Examples
+///
+/// ```
+/// use samurai::{req, Version};
+/// use std::ops::Bound;
+///
+/// let (lower, upper) = req::bounds("^1.2.3").expect("`^1.2.3` should be a single range");
+///
+/// assert_eq!(lower, Bound::Included(Version::new(1, 2, 3)));
+/// assert_eq!(upper, Bound::Excluded(Version::new(2, 0, 0)));
+/// ```
+pub fn bounds(req: &str) -> Result<(Bound<Version>, Bound<Version>)> {
+    req.parse::<VersionReq>()?
+        .bounds()
+        .ok_or_else(|| ParseError::NotARange(req.trim().to_string()))
+}
+
+/// Returns the tightest lower and upper bound of `comparators`, skipping `!=` comparators.
+fn merge_bounds<'a, I: IntoIterator<Item = &'a Comparator>>(
+    comparators: I,
+) -> (Bound<Version>, Bound<Version>) {
+    let mut lower = Bound::Unbounded;
+    let mut upper = Bound::Unbounded;
+
+    for (other_lower, other_upper) in comparators.into_iter().filter_map(Comparator::bounds) {
+        if cmp_bounds(&other_lower, &lower, Ordering::Less) == Ordering::Greater {
+            lower = other_lower;
+        }
+
+        if cmp_bounds(&other_upper, &upper, Ordering::Greater) == Ordering::Less {
+            upper = other_upper;
+        }
+    }
+
+    (lower, upper)
+}
+
+/// Compares two lower or two upper bounds by how much they restrict, where `unbounded` is the
+/// ordering of [`Bound::Unbounded`] relative to any version.
+fn cmp_bounds(a: &Bound<Version>, b: &Bound<Version>, unbounded: Ordering) -> Ordering {
+    match (a, b) {
+        (Bound::Unbounded, Bound::Unbounded) => Ordering::Equal,
+        (Bound::Unbounded, _) => unbounded,
+        (_, Bound::Unbounded) => unbounded.reverse(),
+        (Bound::Included(a), Bound::Included(b)) | (Bound::Excluded(a), Bound::Excluded(b)) => {
+            a.cmp(b)
+        }
+        // An excluded bound is tighter than an included one on the same version, i.e. it is
+        // greater for lower bounds and less for upper bounds.
+        (Bound::Included(a), Bound::Excluded(b)) => a.cmp(b).then(unbounded),
+        (Bound::Excluded(a), Bound::Included(b)) => a.cmp(b).then(unbounded.reverse()),
+    }
+}
+
+/// The kind of constraint a pattern expresses, see [`classify_requirement`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReqKind {
+    /// Matches a single version, e.g. `=1.2.3`.
+    Exact,
+
+    /// Matches compatible versions, e.g. `^1.2.3` or `1.2.3`.
+    Caret,
+
+    /// Matches featureless versions, e.g. `~1.2.3`.
+    Tilde,
+
+    /// Matches versions within comparison bounds, e.g. `>=1.2.0, <2.0.0`.
+    Range,
+
+    /// Matches any version, e.g. `*`.
+    Wildcard,
+}
+
+/// Classifies `req` by the kind of constraint it expresses without evaluating it.
+///
+/// # Errors
+///
+/// This function will return an error if it cannot detect a valid pattern.
+///
+/// # Examples
+///
+/// ```
+/// use samurai::req::{self, ReqKind};
+///
+/// let kind = req::classify_requirement("~1.2").expect("`~1.2` should be a valid pattern");
+///
+/// assert_eq!(kind, ReqKind::Tilde);
+/// ```
+pub fn classify_requirement(req: &str) -> Result<ReqKind> {
+    let req = req.parse::<VersionReq>()?;
+
+    Ok(match req.sets.as_slice() {
+        [comparators] => match comparators.as_slice() {
+            [] => ReqKind::Wildcard,
+            [comparator] => match comparator.operator {
+                Operator::Eq => ReqKind::Exact,
+                Operator::Caret => ReqKind::Caret,
+                Operator::Tilde => ReqKind::Tilde,
+                _ => ReqKind::Range,
+            },
+            _ => ReqKind::Range,
+        },
+        _ => ReqKind::Range,
+    })
+}
+
+/// Extends iterators over [`Version`]s.
+pub trait VersionIterExt: Iterator<Item = Version> + Sized {
+    /// Keeps only the versions that satisfy `req`, parsing it only once.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if it cannot detect a valid pattern.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use samurai::{req::VersionIterExt, Version};
+    ///
+    /// let versions = vec![Version::new(0, 9, 0), Version::new(1, 2, 0), Version::new(2, 0, 0)];
+    /// let matching: Vec<_> = versions
+    ///     .into_iter()
+    ///     .filter_matching("^1.0.0")
+    ///     .expect("`^1.0.0` should be a valid pattern")
+    ///     .collect();
+    ///
+    /// assert!(matching == [Version::new(1, 2, 0)]);
+    /// ```
+    fn filter_matching(self, req: &str) -> Result<impl Iterator<Item = Version>> {
+        let req = req.parse::<VersionReq>()?;
+        Ok(self.filter(move |version| req.matches(version)))
+    }
+}
+
+impl<I: Iterator<Item = Version>> VersionIterExt for I {}
+
+/// Returns the versions that satisfy `req` alongside their indices in `versions`.
+///
+/// # Errors
+///
+/// This function will return an error if it cannot detect a valid pattern.
+///
+/// # Examples
+///
+/// ```
+/// use samurai::{req, Version};
+///
+/// let versions = [Version::new(0, 9, 0), Version::new(1, 2, 0), Version::new(2, 0, 0)];
+/// let matching = req::matching_indexed(&versions, "^1").expect("`^1` should be a valid pattern");
+///
+/// assert_eq!(matching.len(), 1);
+/// assert_eq!(matching[0].0, 1);
+/// ```
+pub fn matching_indexed<'a>(
+    versions: &'a [Version],
+    req: &str,
+) -> Result<Vec<(usize, &'a Version)>> {
+    let req = req.parse::<VersionReq>()?;
+    let mut matching = Vec::new();
+
+    for (index, version) in versions.iter().enumerate() {
+        if req.matches(version) {
+            matching.push((index, version));
+        }
+    }
+
+    Ok(matching)
+}
+
+/// Returns the greatest of `versions` that satisfies `req`.
+///
+/// # Errors
+///
+/// This function will return an error if it cannot detect a valid pattern.
+///
+/// # Examples
+///
+/// ```
+/// use samurai::{req, Version};
+///
+/// let versions = [Version::new(1, 2, 0), Version::new(1, 9, 0), Version::new(2, 0, 0)];
+/// let max = req::max_satisfying(&versions, "^1.0.0").expect("`^1.0.0` should be a valid pattern");
+///
+/// assert_eq!(max, Some(&versions[1]));
+/// ```
+pub fn max_satisfying<'a, I: IntoIterator<Item = &'a Version>>(
+    versions: I,
+    req: &str,
+) -> Result<Option<&'a Version>> {
+    let req = req.parse::<VersionReq>()?;
+    Ok(versions
+        .into_iter()
+        .filter(|version| req.matches(version))
+        .max())
+}
+
+/// Returns the greatest of `candidates` that satisfies `req` in a single pass.
+///
+/// Unlike sorting the candidates first, this consumes them lazily without allocating.
+///
+/// # Examples
+///
+/// ```
+/// use samurai::{req, Version, VersionReq};
+///
+/// let req = "^1.0.0".parse::<VersionReq>().expect("`^1.0.0` should be a valid pattern");
+/// let candidates = (0..3).map(|minor| Version::new(1, minor, 0));
+///
+/// assert!(req::best_match(candidates, &req) == Some(Version::new(1, 2, 0)));
+/// ```
+pub fn best_match<I: IntoIterator<Item = Version>>(
+    candidates: I,
+    req: &VersionReq,
+) -> Option<Version> {
+    candidates
+        .into_iter()
+        .filter(|candidate| req.matches(candidate))
+        .max()
+}
+
+/// Returns the least of `versions` that satisfies `req`.
+///
+/// # Errors
+///
+/// This function will return an error if it cannot detect a valid pattern.
+///
+/// # Examples
+///
+/// ```
+/// use samurai::{req, Version};
+///
+/// let versions = [Version::new(0, 9, 0), Version::new(1, 2, 0), Version::new(1, 9, 0)];
+/// let min = req::min_satisfying(&versions, "^1.0.0").expect("`^1.0.0` should be a valid pattern");
+///
+/// assert_eq!(min, Some(&versions[1]));
+/// ```
+pub fn min_satisfying<'a, I: IntoIterator<Item = &'a Version>>(
+    versions: I,
+    req: &str,
+) -> Result<Option<&'a Version>> {
+    let req = req.parse::<VersionReq>()?;
+    Ok(versions
+        .into_iter()
+        .filter(|version| req.matches(version))
+        .min())
+}
+
+/// Checks whether any of `pool` satisfies `req`, stopping at the first match.
+///
+/// # Examples
+///
+/// ```
+/// use samurai::{req, Version, VersionReq};
+///
+/// let pool = [Version::new(1, 2, 0), Version::new(2, 0, 0)];
+/// let req = "^3.0.0".parse::<VersionReq>().expect("`^3.0.0` should be a valid pattern");
+///
+/// assert!(!req::is_satisfiable(&req, &pool));
+/// ```
+pub fn is_satisfiable<'a, I: IntoIterator<Item = &'a Version>>(req: &VersionReq, pool: I) -> bool {
+    pool.into_iter().any(|version| req.matches(version))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn operator_from_string() -> Result<()> {
+        assert_eq!("=".parse::<Operator>()?, Operator::Eq);
+        assert_eq!("!=".parse::<Operator>()?, Operator::Neq);
+        assert_eq!("<".parse::<Operator>()?, Operator::Lt);
+        assert_eq!(">".parse::<Operator>()?, Operator::Gt);
+        assert_eq!("<=".parse::<Operator>()?, Operator::Lte);
+        assert_eq!(">=".parse::<Operator>()?, Operator::Gte);
+        assert_eq!("^".parse::<Operator>()?, Operator::Caret);
+        assert_eq!("~".parse::<Operator>()?, Operator::Tilde);
+
+        Ok(())
+    }
+
+    #[test]
+    #[should_panic(expected = "operator `=>` not found")]
+    fn operator_from_unknown_string_panics() {
+        "=>".parse::<Operator>().unwrap();
+    }
+
+    #[test]
+    fn matching_indexed_preserves_positions() -> Result<()> {
+        let versions = [
+            Version::new(0, 9, 0),
+            Version::new(1, 0, 0),
+            Version::new(1, 5, 2),
+            Version::new(2, 0, 0),
+            Version::new(1, 9, 9),
+        ];
+
+        let matching = matching_indexed(&versions, "^1")?;
+        let indices: Vec<_> = matching.iter().map(|(index, _)| *index).collect();
+
+        assert_eq!(indices, [1, 2, 4]);
+        assert!(matching
+            .iter()
+            .all(|(index, version)| *version == &versions[*index]));
+
+        Ok(())
+    }
+
+    #[test]
+    fn filter_matching_caret() -> Result<()> {
+        let versions = vec![
+            Version::new(0, 9, 0),
+            Version::new(1, 0, 0),
+            Version::new(1, 5, 2),
+            Version::new(2, 0, 0),
+        ];
+
+        let matching: Vec<_> = versions.into_iter().filter_matching("^1.0.0")?.collect();
+
+        assert!(matching == [Version::new(1, 0, 0), Version::new(1, 5, 2)]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn wildcard_matches_any_version() -> Result<()> {
+        let versions = [
+            Version::new(0, 0, 0),
+            Version::new(0, 9, 1),
+            Version::new(1, 5, 7),
+            Version::new(u32::MAX, u32::MAX, u32::MAX),
+        ];
+
+        for pattern in ["*", "", " * "] {
+            let req = pattern.parse::<VersionReq>()?;
+            assert!(versions.iter().all(|version| req.matches(version)));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn compound_pattern() -> Result<()> {
+        let comma = ">=1.2.0, <2.0.0".parse::<VersionReq>()?;
+        let space = ">=1.2.0 <2.0.0".parse::<VersionReq>()?;
+        let compact = ">=1.2.0,<2.0.0".parse::<VersionReq>()?;
+
+        for req in [comma, space, compact] {
+            assert!(req.matches(&Version::new(1, 2, 0)));
+            assert!(req.matches(&Version::new(1, 9, 9)));
+            assert!(!req.matches(&Version::new(1, 1, 9)));
+            assert!(!req.matches(&Version::new(2, 0, 0)));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn whitespace_after_operator() -> Result<()> {
+        let req = ">= 1.2.0".parse::<VersionReq>()?;
+        assert!(req.matches(&Version::new(1, 2, 0)));
+        assert!(!req.matches(&Version::new(1, 1, 9)));
+
+        let req = ">= 1.2.0, < 2.0.0 || ^ 3.1".parse::<VersionReq>()?;
+        assert_eq!(req.to_string(), ">=1.2.0, <2.0.0 || ^3.1.0");
+
+        assert!(">=".parse::<VersionReq>().is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn describe_compound() -> Result<()> {
+        let req = ">=1.2.0, <2.0.0".parse::<VersionReq>()?;
+        assert_eq!(req.describe(), "at least 1.2.0 and less than 2.0.0");
+
+        Ok(())
+    }
+
+    #[test]
+    fn describe_caret() -> Result<()> {
+        let req = "^1.2.3".parse::<VersionReq>()?;
+        assert_eq!(req.describe(), "compatible with 1.2.3 (>=1.2.3 and <2.0.0)");
+
+        let req = "0.4.1".parse::<VersionReq>()?;
+        assert_eq!(req.describe(), "compatible with 0.4.1 (>=0.4.1 and <0.5.0)");
+
+        Ok(())
+    }
+
+    #[test]
+    fn describe_tilde() -> Result<()> {
+        let req = "~1.2.3".parse::<VersionReq>()?;
+        assert_eq!(req.describe(), "approximately 1.2.3 (>=1.2.3 and <1.3.0)");
+
+        Ok(())
+    }
+
+    #[test]
+    fn describe_at_u32_max() -> Result<()> {
+        let req = "~1.4294967295.0".parse::<VersionReq>()?;
+        assert_eq!(
+            req.describe(),
+            "approximately 1.4294967295.0 (>=1.4294967295.0, unbounded above)"
+        );
+        assert_eq!(
+            req.explain(&Version::new(1, 0, 0)),
+            Err("1.0.0 is below the lower bound 1.4294967295.0".to_string())
+        );
+        assert_eq!(req.explain(&Version::new(2, 0, 0)), Ok(()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn describe_comparison() -> Result<()> {
+        assert_eq!("=1.2.3".parse::<VersionReq>()?.describe(), "exactly 1.2.3");
+        assert_eq!(
+            "!=1.2.3".parse::<VersionReq>()?.describe(),
+            "any version except 1.2.3"
+        );
+        assert_eq!("<1.2".parse::<VersionReq>()?.describe(), "less than 1.2.0");
+        assert_eq!(">1".parse::<VersionReq>()?.describe(), "greater than 1.0.0");
+        assert_eq!("<=1.2.3".parse::<VersionReq>()?.describe(), "at most 1.2.3");
+        assert_eq!(
+            ">=1.2.3".parse::<VersionReq>()?.describe(),
+            "at least 1.2.3"
+        );
+        assert_eq!("*".parse::<VersionReq>()?.describe(), "any version");
+
+        Ok(())
+    }
+
+    #[test]
+    fn explain_out_of_range() -> Result<()> {
+        let req = "^2.0.0".parse::<VersionReq>()?;
+        assert_eq!(
+            req.explain(&Version::new(1, 2, 3)),
+            Err("1.2.3 is below the lower bound 2.0.0".to_string())
+        );
+        assert_eq!(
+            req.explain(&Version::new(3, 0, 0)),
+            Err("3.0.0 is not below the upper bound 3.0.0".to_string())
+        );
+        assert_eq!(req.explain(&Version::new(2, 5, 0)), Ok(()));
+
+        let req = "<=1.0.0".parse::<VersionReq>()?;
+        assert_eq!(
+            req.explain(&Version::new(1, 2, 3)),
+            Err("1.2.3 is above the upper bound 1.0.0".to_string())
+        );
+
+        let req = "=1.0.0".parse::<VersionReq>()?;
+        assert_eq!(
+            req.explain(&Version::new(1, 2, 3)),
+            Err("1.2.3 is not 1.0.0".to_string())
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn intersect_overlapping() -> Result<()> {
+        let a = "^1.2".parse::<VersionReq>()?;
+        let b = ">=1.5".parse::<VersionReq>()?;
+        let both = intersect(&a, &b).expect("`^1.2` and `>=1.5` should overlap");
+
+        assert_eq!(both.describe(), "at least 1.5.0 and less than 2.0.0");
+        assert!(both.matches(&Version::new(1, 5, 0)));
+        assert!(!both.matches(&Version::new(1, 4, 9)));
+        assert!(!both.matches(&Version::new(2, 0, 0)));
+
+        let a = "~1.2.3".parse::<VersionReq>()?;
+        let b = ">1.2.5, <=1.9.0".parse::<VersionReq>()?;
+        let both = intersect(&a, &b).expect("`~1.2.3` and `>1.2.5, <=1.9.0` should overlap");
+
+        assert_eq!(both.describe(), "greater than 1.2.5 and less than 1.3.0");
+
+        let a = ">=1.0.0, <1.0.3".parse::<VersionReq>()?;
+        let b = "!=1.0.0, !=1.0.1".parse::<VersionReq>()?;
+        let both = intersect(&a, &b).expect("1.0.2 should satisfy both");
+
+        assert!(both.matches(&Version::new(1, 0, 2)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn intersect_touching() -> Result<()> {
+        let a = ">=1.2.0".parse::<VersionReq>()?;
+        let b = "<=1.2.0".parse::<VersionReq>()?;
+        let both = intersect(&a, &b).expect("`>=1.2.0` and `<=1.2.0` should overlap");
+
+        assert!(both.is_exact());
+        assert_eq!(both.describe(), "exactly 1.2.0");
+
+        let a = "*".parse::<VersionReq>()?;
+        let both = intersect(&a, &a).expect("`*` should overlap with itself");
+
+        assert_eq!(both.describe(), "any version");
+
+        Ok(())
+    }
+
+    #[test]
+    fn intersect_disjoint() -> Result<()> {
+        let caret = "^1.2".parse::<VersionReq>()?;
+
+        for other in ["^2.0.0", "<1.2.0", ">=2.0.0", "=1.0.0"] {
+            assert!(intersect(&caret, &other.parse()?).is_none());
+        }
+
+        let a = ">1.2.3".parse::<VersionReq>()?;
+        let b = "<1.2.4".parse::<VersionReq>()?;
+        assert!(intersect(&a, &b).is_none());
+
+        let a = "=1.2.3".parse::<VersionReq>()?;
+        let b = "!=1.2.3".parse::<VersionReq>()?;
+        assert!(intersect(&a, &b).is_none());
+
+        let a = ">=1.0.0, <1.0.2".parse::<VersionReq>()?;
+        let b = "!=1.0.0, !=1.0.1".parse::<VersionReq>()?;
+        assert!(intersect(&a, &b).is_none());
+
+        let a = ">1.2.4294967295, <1.3.1".parse::<VersionReq>()?;
+        let b = "!=1.3.0".parse::<VersionReq>()?;
+        assert!(intersect(&a, &b).is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn intersect_at_u32_max() -> Result<()> {
+        let a = "^4294967295.0.0".parse::<VersionReq>()?;
+        let b = ">=4294967295.2.0".parse::<VersionReq>()?;
+        let both = intersect(&a, &b).expect("both should overlap");
+
+        assert_eq!(both.to_string(), ">=4294967295.2.0");
+        assert!(both.matches(&Version::MAX));
+
+        let a = "~1.4294967295.0".parse::<VersionReq>()?;
+        let b = "<2.0.0".parse::<VersionReq>()?;
+        let both = intersect(&a, &b).expect("both should overlap");
+
+        assert_eq!(both.to_string(), ">=1.4294967295.0, <2.0.0");
+        assert!(both.matches(&Version::new(1, u32::MAX, 7)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn partial_without_operator() -> Result<()> {
+        let req = "1".parse::<VersionReq>()?;
+        assert_eq!(req.to_string(), ">=1.0.0, <2.0.0");
+        assert!(req.matches(&Version::new(1, 5, 7)));
+        assert!(!req.matches(&Version::new(2, 0, 0)));
+
+        let req = "1.2".parse::<VersionReq>()?;
+        assert_eq!(req.to_string(), ">=1.2.0, <1.3.0");
+        assert!(req.matches(&Version::new(1, 2, 9)));
+        assert!(!req.matches(&Version::new(1, 3, 0)));
+
+        let req = "0".parse::<VersionReq>()?;
+        assert!(req.matches(&Version::new(0, 9, 0)));
+
+        assert_eq!("^1.2".parse::<VersionReq>()?.to_string(), "^1.2.0");
+
+        Ok(())
+    }
+
+    #[test]
+    fn tilde_with_precision() -> Result<()> {
+        let req = "~1".parse::<VersionReq>()?;
+        assert_eq!(req.to_string(), ">=1.0.0, <2.0.0");
+        assert!(req.matches(&Version::new(1, 9, 0)));
+
+        let req = "~1.2".parse::<VersionReq>()?;
+        assert_eq!(req.to_string(), "~1.2.0");
+        assert!(!req.matches(&Version::new(1, 9, 0)));
+        assert!(req.matches(&Version::new(1, 2, 7)));
+
+        for pattern in ["0", "^0", "~0"] {
+            let req = pattern.parse::<VersionReq>()?;
+            assert_eq!(req.to_string(), ">=0.0.0, <1.0.0");
+            assert!(req.matches(&Version::new(0, 9, 0)));
+        }
+
+        assert_eq!("^1".parse::<VersionReq>()?.to_string(), ">=1.0.0, <2.0.0");
+
+        Ok(())
+    }
+
+    #[test]
+    fn display_canonical() -> Result<()> {
+        let req = "<2.0  >=1.2".parse::<VersionReq>()?;
+        assert_eq!(req.to_string(), ">=1.2.0, <2.0.0");
+
+        let req = "1.2.3".parse::<VersionReq>()?;
+        assert_eq!(req.to_string(), "^1.2.3");
+
+        assert_eq!(
+            "~1.5.4,!=1.5.6".parse::<VersionReq>()?.to_string(),
+            "~1.5.4, !=1.5.6"
+        );
+        assert_eq!("".parse::<VersionReq>()?.to_string(), "*");
+        assert_eq!("latest".parse::<VersionReq>()?.to_string(), "latest");
+
+        Ok(())
+    }
+
+    #[test]
+    fn display_round_trip() -> Result<()> {
+        let versions = [
+            Version::new(0, 5, 0),
+            Version::new(0, 7, 2),
+            Version::new(1, 0, 0),
+            Version::new(1, 2, 0),
+            Version::new(1, 9, 3),
+            Version::new(2, 0, 0),
+        ];
+
+        for pattern in [
+            "^1.2",
+            "<2.0.0, >=1.2.0",
+            "=1.0.0",
+            "*",
+            "latest",
+            ">0.1 <=0.9 !=0.5",
+        ] {
+            let req = pattern.parse::<VersionReq>()?;
+            let reparsed = req.to_string().parse::<VersionReq>()?;
+
+            assert_eq!(reparsed.to_string(), req.to_string());
+            for version in &versions {
+                assert_eq!(reparsed.matches(version), req.matches(version));
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn operator_display() -> Result<()> {
+        for operator in ["=", "!=", "<", ">", "<=", ">=", "^", "~"] {
+            assert_eq!(operator.parse::<Operator>()?.to_string(), operator);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn or_pattern() -> Result<()> {
+        let req = "^1.0.0 || ^2.0.0".parse::<VersionReq>()?;
+
+        assert!(req.matches(&Version::new(1, 5, 7)));
+        assert!(req.matches(&Version::new(2, 0, 3)));
+        assert!(!req.matches(&Version::new(3, 0, 0)));
+        assert!(!req.matches(&Version::new(0, 9, 0)));
+
+        assert_eq!(req.to_string(), "^1.0.0 || ^2.0.0");
+        assert_eq!(
+            "=1.0.0 || >2.0.0".parse::<VersionReq>()?.describe(),
+            "exactly 1.0.0 or greater than 2.0.0"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn or_pattern_with_compound_alternatives() -> Result<()> {
+        let req = ">=1.2.0 <1.5.0||=2.0.0 || *".parse::<VersionReq>()?;
+        assert!(req.matches(&Version::new(9, 0, 0)));
+
+        let req = ">=1.2.0 <1.5.0||=2.0.0".parse::<VersionReq>()?;
+        assert!(req.matches(&Version::new(1, 3, 0)));
+        assert!(req.matches(&Version::new(2, 0, 0)));
+        assert!(!req.matches(&Version::new(1, 5, 0)));
+        assert!(!req.is_exact());
+
+        assert_eq!(
+            req.explain(&Version::new(1, 7, 0)),
+            Err("1.7.0 is not below the upper bound 1.5.0; 1.7.0 is not 2.0.0".to_string())
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn intersect_or_patterns() -> Result<()> {
+        let a = "^1.0.0 || ^2.0.0".parse::<VersionReq>()?;
+        let b = ">=1.5.0, <2.5.0".parse::<VersionReq>()?;
+        let both = intersect(&a, &b).expect("both should overlap");
+
+        assert_eq!(both.to_string(), ">=1.5.0, <2.0.0 || >=2.0.0, <2.5.0");
+
+        let c = "^3.0.0".parse::<VersionReq>()?;
+        assert!(intersect(&a, &c).is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn intersect_drops_keyword() -> Result<()> {
+        let a = "^1".parse::<VersionReq>()?;
+        let latest = "latest".parse::<VersionReq>()?;
+
+        for both in [intersect(&a, &latest), intersect(&latest, &a)] {
+            let both = both.expect("`latest` should match any version");
+
+            assert_eq!(both.to_string(), ">=1.0.0, <2.0.0");
+            assert_eq!(both.resolve_keyword(&[Version::new(1, 2, 0)]), None);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn bounds_of_requirements() -> Result<()> {
+        let v = |major, minor, patch| Version::new(major, minor, patch);
+
+        assert_eq!(
+            bounds("^1.2.3")?,
+            (Bound::Included(v(1, 2, 3)), Bound::Excluded(v(2, 0, 0)))
+        );
+        assert_eq!(
+            bounds("~1.2.3")?,
+            (Bound::Included(v(1, 2, 3)), Bound::Excluded(v(1, 3, 0)))
+        );
+        assert_eq!(
+            bounds("1.2")?,
+            (Bound::Included(v(1, 2, 0)), Bound::Excluded(v(1, 3, 0)))
+        );
+        assert_eq!(
+            bounds(">1.0.0")?,
+            (Bound::Excluded(v(1, 0, 0)), Bound::Unbounded)
+        );
+        assert_eq!(
+            bounds("<=2.0.0, >=1.5.0")?,
+            (Bound::Included(v(1, 5, 0)), Bound::Included(v(2, 0, 0)))
+        );
+        assert_eq!(bounds("*")?, (Bound::Unbounded, Bound::Unbounded));
+        assert_eq!(
+            bounds("^4294967295.0.0")?,
+            (Bound::Included(v(u32::MAX, 0, 0)), Bound::Unbounded)
+        );
+        assert_eq!(
+            bounds("~1.4294967295")?,
+            (Bound::Included(v(1, u32::MAX, 0)), Bound::Unbounded)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    #[should_panic(expected = "is not a single range")]
+    fn bounds_of_alternatives_panics() {
+        bounds("^1.0.0 || ^2.0.0").unwrap();
+    }
+
+    #[test]
+    fn classify() -> Result<()> {
+        assert_eq!(classify_requirement("=1.2.3")?, ReqKind::Exact);
+        assert_eq!(classify_requirement("^1.0")?, ReqKind::Caret);
+        assert_eq!(classify_requirement("1.0.0")?, ReqKind::Caret);
+        assert_eq!(classify_requirement("1.0")?, ReqKind::Range);
+        assert_eq!(classify_requirement("~1.2")?, ReqKind::Tilde);
+        assert_eq!(classify_requirement(">=1.2.0, <2.0.0")?, ReqKind::Range);
+        assert_eq!(classify_requirement("<2")?, ReqKind::Range);
+        assert_eq!(classify_requirement("*")?, ReqKind::Wildcard);
+
+        Ok(())
+    }
+
+    #[test]
+    fn clone_and_compare() -> Result<()> {
+        let req = ">=1.2.0, <2.0.0".parse::<VersionReq>()?;
+
+        assert_eq!(req.clone(), req);
+        assert_ne!(req, "^1.2.0".parse()?);
+        assert_eq!(
+            "**".parse::<VersionReq>().unwrap_err(),
+            ParseError::MissingMajor
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn contradictory_exact_versions() -> Result<()> {
+        assert_eq!(
+            "=1.2.3, =1.2.4".parse::<VersionReq>(),
+            Err(ParseError::Contradiction("=1.2.3, =1.2.4".to_string()))
+        );
+        assert_eq!(
+            "^1 || =1.2.3 =1.2.4".parse::<VersionReq>(),
+            Err(ParseError::Contradiction("=1.2.3 =1.2.4".to_string()))
+        );
+
+        let req = "=1.2.3, >=1.0.0".parse::<VersionReq>()?;
+        assert!(req.matches(&Version::new(1, 2, 3)));
+
+        let req = "=1.2.3, =1.2.3".parse::<VersionReq>()?;
+        assert!(req.matches(&Version::new(1, 2, 3)));
+
+        Ok(())
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot extract the major part")]
+    fn from_malformed_pattern_panics() {
+        "**".parse::<VersionReq>().unwrap();
+    }
+
+    #[test]
+    fn max_and_min_satisfying() -> Result<()> {
+        let versions = [
+            Version::new(1, 4, 0),
+            Version::new(0, 9, 9),
+            Version::new(1, 9, 2),
+            Version::new(2, 0, 0),
+            Version::new(1, 0, 1),
+        ];
+
+        assert_eq!(max_satisfying(&versions, "^1.0.0")?, Some(&versions[2]));
+        assert_eq!(min_satisfying(&versions, "^1.0.0")?, Some(&versions[4]));
+        assert_eq!(max_satisfying(&versions, "^3.0.0")?, None);
+        assert_eq!(min_satisfying(&versions, "^3.0.0")?, None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn resolve_keyword() -> Result<()> {
+        let pool = [
+            Version::new(1, 4, 0),
+            Version::new(2, 5, 1),
+            Version::new(2, 0, 0),
+        ];
+
+        let latest = " latest ".parse::<VersionReq>()?;
+
+        assert_eq!(latest.resolve_keyword(&pool), Some(Version::new(2, 5, 1)));
+        assert_eq!(latest.resolve_keyword(&[]), None);
+        assert!(latest.matches(&Version::new(0, 1, 0)));
+        assert_eq!(latest.describe(), "the latest version");
+        assert!("stable".parse::<VersionReq>().is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn resolve_keyword_on_requirement() -> Result<()> {
+        let req = "^1.0.0".parse::<VersionReq>()?;
+
+        assert_eq!(req.resolve_keyword(&[Version::new(1, 2, 0)]), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn is_satisfiable_by_pool() -> Result<()> {
+        let pool = [
+            Version::new(1, 4, 0),
+            Version::new(2, 0, 0),
+            Version::new(2, 5, 1),
+        ];
+
+        assert!(is_satisfiable(&"^2.0.0".parse()?, &pool));
+        assert!(!is_satisfiable(&"^3.0.0".parse()?, &pool));
+        assert!(!is_satisfiable(&"*".parse()?, &[]));
+
+        Ok(())
+    }
+
+    #[test]
+    fn best_match_over_adaptors() -> Result<()> {
+        let req = "^1.2.0".parse::<VersionReq>()?;
+        let candidates = (0..3)
+            .flat_map(|major| (0..5).map(move |minor| (major, minor)))
+            .map(|(major, minor)| Version::new(major, minor, 7));
+
+        assert_eq!(best_match(candidates, &req), Some(Version::new(1, 4, 7)));
+        assert_eq!(best_match(Vec::new(), &req), None);
+
+        Ok(())
+    }
+
+    #[test]
+    #[should_panic(expected = "not found")]
+    fn matching_indexed_with_invalid_pattern_panics() {
+        matching_indexed(&[Version::new(1, 0, 0)], "seeya1").unwrap();
+    }
+}
@@ -1,8 +1,91 @@
-use std::{cmp::Ordering, result, str::FromStr};
+use crate::{
+    error::{InvalidVersionError, OverflowError},
+    ParseError, VersionReq,
+};
+use std::{
+    cmp::Ordering,
+    env, fmt,
+    num::{IntErrorKind, ParseIntError},
+    result,
+    str::{self, FromStr},
+};
+
+type Result<T> = result::Result<T, ParseError>;
+
+/// Identifies a part of a [`Version`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Increment {
+    /// The major part.
+    Major,
+
+    /// The minor part.
+    Minor,
+
+    /// The patch part.
+    Patch,
+}
+
+impl FromStr for Increment {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> result::Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "major" => Ok(Self::Major),
+            "minor" => Ok(Self::Minor),
+            "patch" => Ok(Self::Patch),
+            _ => Err(ParseError::UnknownLevel(s.to_string())),
+        }
+    }
+}
+
+/// What to do when bumping a part that is already at `u32::MAX`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OverflowPolicy {
+    /// Return the version unchanged.
+    Saturate,
 
-type Result<T> = result::Result<T, String>;
+    /// Return an [`OverflowError`].
+    Error,
 
-#[derive(Eq)]
+    /// Wrap the part around to `0`, resetting the lower parts as usual.
+    Wrap,
+}
+
+/// How many parts were given when parsing a [`Version`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Precision {
+    /// Only the major part was given, e.g. `1`.
+    Major,
+
+    /// The major and minor parts were given, e.g. `1.2`.
+    Minor,
+
+    /// All three parts were given, e.g. `1.2.3`.
+    Full,
+}
+
+/// A semantic version consisting of a major, a minor and a patch part.
+///
+/// A version may carry build metadata (e.g. the `build.7` of `1.2.3+build.7`), which is kept but
+/// ignored when comparing versions. Versions are totally ordered by precedence, so collections of
+/// them can be sorted with [`slice::sort`] (or [`slice::sort_by`] with [`Ordering::reverse`] for
+/// descending order).
+///
+/// Since 0.4.0 a version also remembers how many parts it was parsed from, so it can no longer be
+/// built with a struct literal; use [`Version::new`] instead. Equal versions may then render
+/// differently through [`Version::display_original`], e.g. `1.2` and `1.2.0`.
+///
+/// # Examples
+///
+/// ```
+/// use samurai::Version;
+///
+/// let mut versions = vec![Version::new(1, 2, 0), Version::new(0, 9, 0), Version::new(1, 0, 0)];
+/// versions.sort();
+///
+/// assert!(versions == [Version::new(0, 9, 0), Version::new(1, 0, 0), Version::new(1, 2, 0)]);
+/// ```
+#[derive(Clone, Eq)]
 pub struct Version {
     /// Represents incompatible API changes.
     pub major: u32,
@@ -12,9 +95,27 @@ pub struct Version {
 
     /// Represents bug fixes in a backwards compatible manner.
     pub patch: u32,
+
+    /// Number of parts that were present when parsing, ignored by comparisons.
+    parts: usize,
+
+    /// Identifiers of the build metadata, ignored by comparisons.
+    build: Vec<String>,
 }
 
 impl Version {
+    /// The `0.0.0` version.
+    pub const ZERO: Self = Self::new(0, 0, 0);
+
+    /// The least possible version, same as [`Version::ZERO`].
+    pub const MIN: Self = Self::ZERO;
+
+    /// The greatest possible version, with `u32::MAX` in all parts.
+    pub const MAX: Self = Self::new(u32::MAX, u32::MAX, u32::MAX);
+
+    /// The value reserved as a sentinel, which [`Version::try_new`] rejects in any part.
+    pub const RESERVED: u32 = u32::MAX;
+
     /// Creates a new [`Version`].
     ///
     /// # Examples
@@ -28,16 +129,182 @@ impl Version {
     /// assert_eq!(version.minor, 5);
     /// assert_eq!(version.patch, 7);
     /// ```
-    pub fn new(major: u32, minor: u32, patch: u32) -> Self {
+    pub const fn new(major: u32, minor: u32, patch: u32) -> Self {
         Self {
             major,
             minor,
             patch,
+            parts: 3,
+            build: Vec::new(),
+        }
+    }
+
+    /// Creates a new [`Version`] in `const` context without any validation, same as
+    /// [`Version::new`].
+    ///
+    /// Use [`Version::try_new`] to reject `0.0.0` and reserved parts instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use samurai::Version;
+    ///
+    /// const MIN_SUPPORTED: Version = Version::new_unchecked(1, 2, 0);
+    ///
+    /// assert_eq!(MIN_SUPPORTED.minor, 2);
+    /// ```
+    pub const fn new_unchecked(major: u32, minor: u32, patch: u32) -> Self {
+        Self::new(major, minor, patch)
+    }
+
+    /// Creates a new [`Version`], rejecting `0.0.0` and any part equal to [`Version::RESERVED`].
+    ///
+    /// Since [`Version::RESERVED`] is `u32::MAX`, this rejects [`Version::MAX`] as well.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if all parts are zero or if any part is reserved.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use samurai::{error::InvalidVersionError, version::Increment, Version};
+    ///
+    /// assert!(Version::try_new(1, 5, 7).is_ok());
+    /// assert_eq!(Version::try_new(0, 0, 0), Err(InvalidVersionError::Zero));
+    /// assert_eq!(
+    ///     Version::try_new(1, Version::RESERVED, 7),
+    ///     Err(InvalidVersionError::ReservedPart { level: Increment::Minor })
+    /// );
+    /// ```
+    pub fn try_new(
+        major: u32,
+        minor: u32,
+        patch: u32,
+    ) -> result::Result<Self, InvalidVersionError> {
+        if (major, minor, patch) == (0, 0, 0) {
+            return Err(InvalidVersionError::Zero);
+        }
+
+        let levels = [
+            (major, Increment::Major),
+            (minor, Increment::Minor),
+            (patch, Increment::Patch),
+        ];
+
+        if let Some((_, level)) = levels.into_iter().find(|(part, _)| *part == Self::RESERVED) {
+            return Err(InvalidVersionError::ReservedPart { level });
+        }
+
+        Ok(Self::new(major, minor, patch))
+    }
+
+    /// Renders instance of [`Version`] in its canonical form, which is suitable for persisting.
+    ///
+    /// The canonical form always has all three parts and no leading zeros, regardless of how the
+    /// version was written when parsing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use samurai::Version;
+    ///
+    /// let version = " 01.2 ".parse::<Version>().expect("` 01.2 ` should be a valid version");
+    ///
+    /// assert_eq!(version.normalize(), "1.2.0");
+    /// ```
+    pub fn normalize(&self) -> String {
+        self.to_string()
+    }
+
+    /// Renders instance of [`Version`] with as many parts as it was parsed from.
+    ///
+    /// Unlike [`Display`](fmt::Display), which always renders all three parts, this keeps implicit
+    /// parts implicit. Versions that were not parsed are rendered in full.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use samurai::Version;
+    ///
+    /// let version = "1.2".parse::<Version>().expect("`1.2` should be a valid version");
+    ///
+    /// assert_eq!(version.to_string(), "1.2.0");
+    /// assert_eq!(version.display_original(), "1.2");
+    /// ```
+    pub fn display_original(&self) -> String {
+        let core = match self.parts {
+            1 => self.major.to_string(),
+            2 => format!("{}.{}", self.major, self.minor),
+            _ => return self.to_string(),
+        };
+
+        if self.build.is_empty() {
+            return core;
+        }
+
+        format!("{}+{}", core, self.build.join("."))
+    }
+
+    /// Returns the identifiers of the build metadata of instance of [`Version`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use samurai::Version;
+    ///
+    /// let version = "1.2.3+build.7".parse::<Version>().expect("`1.2.3+build.7` should be valid");
+    ///
+    /// assert_eq!(version.build(), ["build", "7"]);
+    /// assert!(version == Version::new(1, 2, 3));
+    /// ```
+    pub fn build(&self) -> &[String] {
+        &self.build
+    }
+
+    /// Returns how many parts instance of [`Version`] was parsed from.
+    ///
+    /// Versions that were not parsed are [`Precision::Full`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use samurai::{version::Precision, Version};
+    ///
+    /// let version = "1.2".parse::<Version>().expect("`1.2` should be a valid version");
+    ///
+    /// assert_eq!(version.precision(), Precision::Minor);
+    /// assert_eq!(Version::new(1, 2, 0).precision(), Precision::Full);
+    /// ```
+    pub fn precision(&self) -> Precision {
+        match self.parts {
+            1 => Precision::Major,
+            2 => Precision::Minor,
+            _ => Precision::Full,
         }
     }
 
+    /// Checks whether instance of [`Version`] is in initial development, i.e. its major part is `0`
+    /// and anything may change at any time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use samurai::Version;
+    ///
+    /// assert!(Version::new(0, 9, 0).is_initial_development());
+    /// assert!(!Version::new(1, 0, 0).is_initial_development());
+    /// ```
+    pub fn is_initial_development(&self) -> bool {
+        self.major == 0
+    }
+
     /// Checks whether there haven't been any breaking changes since `other`.
     ///
+    /// This is asymmetric: instance of [`Version`] has to be at least `other`, so `1.5.7` is
+    /// compatible with `1.2.9` but not the other way around. Use
+    /// [`Version::compatible_either_way`] if the direction does not matter.
+    ///
     /// # Examples
     ///
     /// ```
@@ -55,7 +322,24 @@ impl Version {
             return self.is_featureless(other);
         }
 
-        self >= other && self < &Self::new(other.major + 1, 0, 0)
+        self >= other && other.checked_bump_major().is_none_or(|upper| *self < upper)
+    }
+
+    /// Checks whether instance of [`Version`] and `other` are compatible in either direction.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use samurai::Version;
+    ///
+    /// let older = Version::new(1, 2, 9);
+    /// let newer = Version::new(1, 5, 7);
+    ///
+    /// assert!(!older.is_compatible(&newer));
+    /// assert!(older.compatible_either_way(&newer));
+    /// ```
+    pub fn compatible_either_way(&self, other: &Self) -> bool {
+        self.is_compatible(other) || other.is_compatible(self)
     }
 
     /// Checks whether there haven't been any feature implementations since `other`.
@@ -73,215 +357,2765 @@ impl Version {
     /// assert!(!version.is_featureless(&other2));
     /// ```
     pub fn is_featureless(&self, other: &Self) -> bool {
-        self >= other && self < &Self::new(other.major, other.minor + 1, 0)
+        self >= other && other.checked_bump_minor().is_none_or(|upper| *self < upper)
+    }
+
+    /// Checks whether instance of [`Version`] and `other` have the same major part.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use samurai::Version;
+    ///
+    /// assert!(Version::new(1, 2, 3).eq_major(&Version::new(1, 9, 0)));
+    /// assert!(!Version::new(1, 2, 3).eq_major(&Version::new(2, 2, 3)));
+    /// ```
+    pub fn eq_major(&self, other: &Self) -> bool {
+        self.major == other.major
+    }
+
+    /// Checks whether instance of [`Version`] and `other` have the same major and minor parts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use samurai::Version;
+    ///
+    /// assert!(Version::new(1, 2, 3).eq_minor(&Version::new(1, 2, 9)));
+    /// assert!(!Version::new(1, 2, 3).eq_minor(&Version::new(1, 3, 0)));
+    /// ```
+    pub fn eq_minor(&self, other: &Self) -> bool {
+        self.major == other.major && self.minor == other.minor
+    }
+
+    /// Checks whether instance of [`Version`] is in `low..high`, i.e. `low <= self < high`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use samurai::Version;
+    ///
+    /// let low = Version::new(1, 0, 0);
+    /// let high = Version::new(2, 0, 0);
+    ///
+    /// assert!(Version::new(1, 0, 0).in_range(&low, &high));
+    /// assert!(!Version::new(2, 0, 0).in_range(&low, &high));
+    /// ```
+    pub fn in_range(&self, low: &Self, high: &Self) -> bool {
+        low <= self && self < high
+    }
+
+    /// Checks whether instance of [`Version`] is in `low..=high`, i.e. `low <= self <= high`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use samurai::Version;
+    ///
+    /// let low = Version::new(1, 0, 0);
+    /// let high = Version::new(2, 0, 0);
+    ///
+    /// assert!(Version::new(2, 0, 0).in_range_inclusive(&low, &high));
+    /// assert!(!Version::new(2, 0, 1).in_range_inclusive(&low, &high));
+    /// ```
+    pub fn in_range_inclusive(&self, low: &Self, high: &Self) -> bool {
+        low <= self && self <= high
+    }
+
+    /// Returns `min` if instance of [`Version`] is below it, `max` if it is above it and a copy of
+    /// instance of [`Version`] otherwise.
+    ///
+    /// This is [`Ord::clamp`] without having to give up ownership of any of the versions.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `min > max`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use samurai::Version;
+    ///
+    /// let min = Version::new(1, 2, 0);
+    /// let max = Version::new(1, 8, 0);
+    ///
+    /// assert!(Version::new(2, 0, 0).clamped(&min, &max) == max);
+    /// ```
+    pub fn clamped(&self, min: &Self, max: &Self) -> Self {
+        self.clone().clamp(min.clone(), max.clone())
+    }
+
+    /// Checks whether `version` falls in the caret range of `base`, i.e. `^base`.
+    ///
+    /// This is the same as `version.is_compatible(base)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use samurai::Version;
+    ///
+    /// let base = Version::new(1, 2, 9);
+    ///
+    /// assert!(Version::in_caret_range(&base, &Version::new(1, 5, 7)));
+    /// assert!(!Version::in_caret_range(&base, &Version::new(2, 0, 0)));
+    /// ```
+    pub fn in_caret_range(base: &Self, version: &Self) -> bool {
+        version.is_compatible(base)
+    }
+
+    /// Checks whether `version` falls in the tilde range of `base`, i.e. `~base`.
+    ///
+    /// This is the same as `version.is_featureless(base)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use samurai::Version;
+    ///
+    /// let base = Version::new(1, 5, 4);
+    ///
+    /// assert!(Version::in_tilde_range(&base, &Version::new(1, 5, 7)));
+    /// assert!(!Version::in_tilde_range(&base, &Version::new(1, 6, 2)));
+    /// ```
+    pub fn in_tilde_range(base: &Self, version: &Self) -> bool {
+        version.is_featureless(base)
+    }
+
+    /// Returns a key shared by all versions on the same compatible line as instance of
+    /// [`Version`], e.g. for grouping them in a [`HashMap`](std::collections::HashMap).
+    ///
+    /// That is the major part, plus the minor part before `1.0.0` since only the same minor line
+    /// is compatible there.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use samurai::Version;
+    ///
+    /// assert_eq!(Version::new(1, 2, 0).compatibility_key(), (1, None));
+    /// assert_eq!(Version::new(0, 2, 1).compatibility_key(), (0, Some(2)));
+    /// ```
+    pub fn compatibility_key(&self) -> (u32, Option<u32>) {
+        if self.major == 0 {
+            return (0, Some(self.minor));
+        }
+
+        (self.major, None)
+    }
+
+    /// Returns the oldest [`Version`] that instance of [`Version`] is still compatible with.
+    ///
+    /// That is `major.0.0`, or `0.minor.0` before `1.0.0` since only the same minor line is
+    /// compatible there.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use samurai::Version;
+    ///
+    /// let version = Version::new(1, 5, 3);
+    /// let earliest = version.earliest_compatible();
+    ///
+    /// assert!(earliest == Version::new(1, 0, 0));
+    /// assert!(version.is_compatible(&earliest));
+    /// ```
+    pub fn earliest_compatible(&self) -> Self {
+        if self.major == 0 {
+            return Self::new(0, self.minor, 0);
+        }
+
+        Self::new(self.major, 0, 0)
+    }
+
+    /// Returns the lowest [`Version`] that is a breaking change from instance of [`Version`], or
+    /// [`None`] if the part to increment is already `u32::MAX`.
+    ///
+    /// That is the next major, or the next minor before `1.0.0`, the same upper bound as
+    /// [`Range::caret`](crate::Range::caret) and [`Version::is_compatible`] use.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use samurai::Version;
+    ///
+    /// assert!(Version::new(1, 2, 3).next_breaking() == Some(Version::new(2, 0, 0)));
+    /// assert!(Version::new(0, 2, 3).next_breaking() == Some(Version::new(0, 3, 0)));
+    /// assert!(Version::new(0, 0, 3).next_breaking() == Some(Version::new(0, 1, 0)));
+    /// ```
+    pub fn next_breaking(&self) -> Option<Self> {
+        if self.major == 0 {
+            return self.checked_bump_minor();
+        }
+
+        self.checked_bump_major()
+    }
+
+    /// Returns the greatest of `candidates` that can replace instance of [`Version`] without any
+    /// breaking changes.
+    ///
+    /// Since [`Version::is_compatible`] is asymmetric, note that this picks the candidates for
+    /// which `candidate.is_compatible(self)` holds, i.e. upgrades within the same compatible line.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use samurai::Version;
+    ///
+    /// let version = Version::new(1, 2, 0);
+    /// let candidates = [Version::new(1, 5, 0), Version::new(2, 0, 0), Version::new(1, 9, 3)];
+    ///
+    /// assert!(version.highest_compatible(&candidates) == Some(&candidates[2]));
+    /// ```
+    pub fn highest_compatible<'a>(&self, candidates: &'a [Self]) -> Option<&'a Self> {
+        candidates
+            .iter()
+            .filter(|candidate| candidate.is_compatible(self))
+            .max()
+    }
+
+    /// Returns the candidate nearest to instance of [`Version`], e.g. for "did you mean?"
+    /// suggestions.
+    ///
+    /// Distances are compared part by part, so any difference in the major part outweighs any
+    /// difference in the minor part, which in turn outweighs any difference in the patch part. The
+    /// direction does not matter and ties go to the earliest candidate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use samurai::Version;
+    ///
+    /// let version = Version::new(1, 2, 3);
+    /// let candidates = [Version::new(1, 3, 0), Version::new(1, 2, 9), Version::new(2, 2, 3)];
+    ///
+    /// assert!(version.closest(&candidates) == Some(&candidates[1]));
+    /// ```
+    pub fn closest<'a>(&self, candidates: &'a [Self]) -> Option<&'a Self> {
+        candidates.iter().min_by_key(|candidate| {
+            (
+                self.major.abs_diff(candidate.major),
+                self.minor.abs_diff(candidate.minor),
+                self.patch.abs_diff(candidate.patch),
+            )
+        })
+    }
+
+    /// Returns instance of [`Version`] with its major part set to `major`.
+    ///
+    /// Unlike bumping, the lower parts are left untouched.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use samurai::Version;
+    ///
+    /// assert!(Version::new(1, 2, 3).with_major(5) == Version::new(5, 2, 3));
+    /// ```
+    pub fn with_major(&self, major: u32) -> Self {
+        Self {
+            major,
+            parts: 3,
+            ..self.clone()
+        }
+    }
+
+    /// Returns instance of [`Version`] with its minor part set to `minor`.
+    ///
+    /// Unlike bumping, the patch part is left untouched.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use samurai::Version;
+    ///
+    /// assert!(Version::new(1, 2, 3).with_minor(5) == Version::new(1, 5, 3));
+    /// ```
+    pub fn with_minor(&self, minor: u32) -> Self {
+        Self {
+            minor,
+            parts: 3,
+            ..self.clone()
+        }
+    }
+
+    /// Returns instance of [`Version`] with its patch part set to `patch`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use samurai::Version;
+    ///
+    /// assert!(Version::new(1, 2, 3).with_patch(5) == Version::new(1, 2, 5));
+    /// ```
+    pub fn with_patch(&self, patch: u32) -> Self {
+        Self {
+            patch,
+            parts: 3,
+            ..self.clone()
+        }
+    }
+
+    /// Returns instance of [`Version`] with its minor and patch parts reset, e.g. for bucketing
+    /// versions by major line.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use samurai::Version;
+    ///
+    /// assert!(Version::new(1, 2, 3).truncate_to_major() == Version::new(1, 0, 0));
+    /// ```
+    pub fn truncate_to_major(&self) -> Self {
+        Self::new(self.major, 0, 0)
+    }
+
+    /// Returns instance of [`Version`] with its patch part reset, e.g. for bucketing versions by
+    /// minor line.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use samurai::Version;
+    ///
+    /// assert!(Version::new(1, 2, 3).truncate_to_minor() == Version::new(1, 2, 0));
+    /// ```
+    pub fn truncate_to_minor(&self) -> Self {
+        Self::new(self.major, self.minor, 0)
+    }
+
+    /// Returns instance of [`Version`] with its build metadata stripped, leaving the parts as they
+    /// are.
+    ///
+    /// Unlike bumping, this finalizes the version at hand rather than moving to the next one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use samurai::Version;
+    ///
+    /// let version = "1.2.0+build.7".parse::<Version>().expect("`1.2.0+build.7` should be valid");
+    ///
+    /// assert_eq!(version.release().to_string(), "1.2.0");
+    /// ```
+    pub fn release(&self) -> Self {
+        Self {
+            build: Vec::new(),
+            ..self.clone()
+        }
+    }
+
+    /// Returns instance of [`Version`] with its major part incremented and the lower parts reset,
+    /// or [`None`] if the major part would overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use samurai::Version;
+    ///
+    /// let version = Version::new(1, 5, 7);
+    ///
+    /// assert!(version.checked_bump_major() == Some(Version::new(2, 0, 0)));
+    /// assert!(Version::new(u32::MAX, 0, 0).checked_bump_major().is_none());
+    /// ```
+    pub fn checked_bump_major(&self) -> Option<Self> {
+        Some(Self::new(self.major.checked_add(1)?, 0, 0))
+    }
+
+    /// Returns instance of [`Version`] with its minor part incremented and the patch part reset,
+    /// or [`None`] if the minor part would overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use samurai::Version;
+    ///
+    /// let version = Version::new(1, 5, 7);
+    ///
+    /// assert!(version.checked_bump_minor() == Some(Version::new(1, 6, 0)));
+    /// assert!(Version::new(1, u32::MAX, 0).checked_bump_minor().is_none());
+    /// ```
+    pub fn checked_bump_minor(&self) -> Option<Self> {
+        Some(Self::new(self.major, self.minor.checked_add(1)?, 0))
+    }
+
+    /// Returns instance of [`Version`] with its patch part incremented, or [`None`] if the patch
+    /// part would overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use samurai::Version;
+    ///
+    /// let version = Version::new(1, 5, 7);
+    ///
+    /// assert!(version.checked_bump_patch() == Some(Version::new(1, 5, 8)));
+    /// assert!(Version::new(1, 5, u32::MAX).checked_bump_patch().is_none());
+    /// ```
+    pub fn checked_bump_patch(&self) -> Option<Self> {
+        Some(Self::new(
+            self.major,
+            self.minor,
+            self.patch.checked_add(1)?,
+        ))
+    }
+
+    /// Returns the version one patch below instance of [`Version`], or [`None`] if the patch part
+    /// is `0`.
+    ///
+    /// There is no simple predecessor of e.g. `1.2.0`, since any `1.1.x` would be less than it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use samurai::Version;
+    ///
+    /// assert!(Version::new(1, 2, 3).predecessor_patch() == Some(Version::new(1, 2, 2)));
+    /// assert!(Version::new(1, 2, 0).predecessor_patch().is_none());
+    /// ```
+    pub fn predecessor_patch(&self) -> Option<Self> {
+        Some(Self::new(
+            self.major,
+            self.minor,
+            self.patch.checked_sub(1)?,
+        ))
+    }
+
+    /// Same as [`Version::checked_bump_major`] but returns instance of [`Version`] unchanged
+    /// rather than [`None`] once the major part is at `u32::MAX`.
+    pub fn saturating_bump_major(&self) -> Self {
+        self.checked_bump_major().unwrap_or_else(|| self.clone())
+    }
+
+    /// Same as [`Version::checked_bump_minor`] but returns instance of [`Version`] unchanged
+    /// rather than [`None`] once the minor part is at `u32::MAX`.
+    pub fn saturating_bump_minor(&self) -> Self {
+        self.checked_bump_minor().unwrap_or_else(|| self.clone())
+    }
+
+    /// Same as [`Version::checked_bump_patch`] but returns instance of [`Version`] unchanged
+    /// rather than [`None`] once the patch part is at `u32::MAX`.
+    pub fn saturating_bump_patch(&self) -> Self {
+        self.checked_bump_patch().unwrap_or_else(|| self.clone())
+    }
+
+    /// Bumps instance of [`Version`] at the level named by `level`, e.g. from a CLI argument.
+    ///
+    /// The level name is case-insensitive and the bump saturates like
+    /// [`Version::saturating_bump_major`] and friends.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if `level` is not `major`, `minor` or `patch`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use samurai::Version;
+    ///
+    /// let version = Version::new(1, 5, 7);
+    ///
+    /// let bumped = version.bump_str("Minor").expect("`Minor` should be a valid level");
+    ///
+    /// assert!(bumped == Version::new(1, 6, 0));
+    /// ```
+    pub fn bump_str(&self, level: &str) -> Result<Self> {
+        Ok(match level.parse()? {
+            Increment::Major => self.saturating_bump_major(),
+            Increment::Minor => self.saturating_bump_minor(),
+            Increment::Patch => self.saturating_bump_patch(),
+        })
+    }
+
+    /// Bumps instance of [`Version`] at `level`, handling overflow according to `policy`.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the part at `level` is `u32::MAX` and `policy` is
+    /// [`OverflowPolicy::Error`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use samurai::{
+    ///     version::{Increment, OverflowPolicy},
+    ///     Version,
+    /// };
+    ///
+    /// let version = Version::new(1, u32::MAX, 7);
+    ///
+    /// assert_eq!(
+    ///     version.try_bump(Increment::Minor, OverflowPolicy::Wrap),
+    ///     Ok(Version::new(1, 0, 0))
+    /// );
+    /// assert!(version.try_bump(Increment::Minor, OverflowPolicy::Error).is_err());
+    /// ```
+    pub fn try_bump(
+        &self,
+        level: Increment,
+        policy: OverflowPolicy,
+    ) -> result::Result<Self, OverflowError> {
+        let bumped = match level {
+            Increment::Major => self.checked_bump_major(),
+            Increment::Minor => self.checked_bump_minor(),
+            Increment::Patch => self.checked_bump_patch(),
+        };
+
+        if let Some(bumped) = bumped {
+            return Ok(bumped);
+        }
+
+        match policy {
+            OverflowPolicy::Saturate => Ok(self.clone()),
+            OverflowPolicy::Error => Err(OverflowError { level }),
+            OverflowPolicy::Wrap => Ok(match level {
+                Increment::Major => Self::new(0, 0, 0),
+                Increment::Minor => Self::new(self.major, 0, 0),
+                Increment::Patch => Self::new(self.major, self.minor, 0),
+            }),
+        }
+    }
+
+    /// Returns a [`VersionReq`] that is only satisfied by instance of [`Version`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use samurai::Version;
+    ///
+    /// let version = Version::new(1, 5, 7);
+    /// let req = version.as_exact_req();
+    ///
+    /// assert!(req.is_exact());
+    /// assert!(req.matches(&version));
+    /// assert!(!req.matches(&Version::new(1, 5, 8)));
+    /// ```
+    pub fn as_exact_req(&self) -> VersionReq {
+        VersionReq::exact(self.clone())
+    }
+
+    /// Converts instance of [`Version`] into a [`WideVersion`].
+    pub fn to_wide(&self) -> WideVersion {
+        WideVersion::from(self.clone())
+    }
+
+    /// Converts instance of [`Version`] into a `(major, minor, patch)` tuple.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use samurai::Version;
+    ///
+    /// let (major, minor, patch) = Version::new(1, 5, 7).to_tuple();
+    ///
+    /// assert_eq!((major, minor, patch), (1, 5, 7));
+    /// assert_eq!(Version::from((major, minor, patch)), Version::new(1, 5, 7));
+    /// ```
+    pub const fn to_tuple(&self) -> (u32, u32, u32) {
+        (self.major, self.minor, self.patch)
+    }
+
+    /// Interprets instance of [`Version`] as a `YYYY.MM.DD` date.
+    ///
+    /// Returns `(year, month, day)`, or [`None`] if the minor part is not in `1..=12` or the patch
+    /// part is not in `1..=31`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use samurai::Version;
+    ///
+    /// assert_eq!(Version::new(2024, 1, 15).as_calver_date(), Some((2024, 1, 15)));
+    /// assert_eq!(Version::new(2024, 13, 1).as_calver_date(), None);
+    /// ```
+    pub fn as_calver_date(&self) -> Option<(u32, u32, u32)> {
+        if (1..=12).contains(&self.minor) && (1..=31).contains(&self.patch) {
+            Some(self.to_tuple())
+        } else {
+            None
+        }
+    }
+
+    /// Returns the most significant part that differs between instance of [`Version`] and `other`.
+    ///
+    /// The direction does not matter and [`None`] is returned if both are equal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use samurai::{version::Increment, Version};
+    ///
+    /// let version = Version::new(1, 5, 7);
+    ///
+    /// assert_eq!(version.diff(&Version::new(1, 6, 0)), Some(Increment::Minor));
+    /// assert_eq!(version.diff(&Version::new(1, 5, 7)), None);
+    /// ```
+    pub fn diff(&self, other: &Self) -> Option<Increment> {
+        if self.major != other.major {
+            Some(Increment::Major)
+        } else if self.minor != other.minor {
+            Some(Increment::Minor)
+        } else if self.patch != other.patch {
+            Some(Increment::Patch)
+        } else {
+            None
+        }
+    }
+
+    /// Returns how many minor releases instance of [`Version`] is ahead of `other`, or [`None`]
+    /// if they are not on the same major line.
+    ///
+    /// The result is negative when instance of [`Version`] is behind `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use samurai::Version;
+    ///
+    /// let version = Version::new(1, 2, 3);
+    ///
+    /// assert_eq!(version.minor_delta(&Version::new(1, 7, 0)), Some(-5));
+    /// assert_eq!(version.minor_delta(&Version::new(2, 2, 3)), None);
+    /// ```
+    pub fn minor_delta(&self, other: &Self) -> Option<i64> {
+        if self.major != other.major {
+            return None;
+        }
+
+        Some(i64::from(self.minor) - i64::from(other.minor))
+    }
+
+    /// Returns how many patch releases instance of [`Version`] is ahead of `other`, or [`None`]
+    /// if they are not on the same minor line.
+    ///
+    /// The result is negative when instance of [`Version`] is behind `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use samurai::Version;
+    ///
+    /// let version = Version::new(1, 2, 3);
+    ///
+    /// assert_eq!(version.patch_delta(&Version::new(1, 2, 1)), Some(2));
+    /// assert_eq!(version.patch_delta(&Version::new(1, 3, 3)), None);
+    /// ```
+    pub fn patch_delta(&self, other: &Self) -> Option<i64> {
+        if self.major != other.major || self.minor != other.minor {
+            return None;
+        }
+
+        Some(i64::from(self.patch) - i64::from(other.patch))
+    }
+
+    /// Compares instance of [`Version`] with `other` only up to the given `level`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use samurai::{version::Increment, Version};
+    /// use std::cmp::Ordering;
+    ///
+    /// let version = Version::new(1, 5, 7);
+    /// let other = Version::new(1, 5, 9);
+    ///
+    /// assert_eq!(version.cmp_at_level(&other, Increment::Minor), Ordering::Equal);
+    /// assert_eq!(version.cmp_at_level(&other, Increment::Patch), Ordering::Less);
+    /// ```
+    pub fn cmp_at_level(&self, other: &Self, level: Increment) -> Ordering {
+        match level {
+            Increment::Major => self.major.cmp(&other.major),
+            Increment::Minor => (self.major, self.minor).cmp(&(other.major, other.minor)),
+            Increment::Patch => self.cmp(other),
+        }
+    }
+
+    /// Compares instance of [`Version`] with `other` like [`Ord::cmp`], then by the identifiers of
+    /// the build metadata as a last tie-breaker.
+    ///
+    /// [`Ord::cmp`] follows the precedence of the spec, which ignores build metadata, so versions
+    /// that differ only in it compare equal. This ordering tells them apart, comparing identifiers
+    /// lexically, which makes sorting deterministic.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use samurai::Version;
+    /// use std::cmp::Ordering;
+    ///
+    /// let a = "1.0.0+001".parse::<Version>().expect("`1.0.0+001` should be a valid version");
+    /// let b = "1.0.0+002".parse::<Version>().expect("`1.0.0+002` should be a valid version");
+    ///
+    /// assert_eq!(a.cmp(&b), Ordering::Equal);
+    /// assert_eq!(a.total_cmp(&b), Ordering::Less);
+    /// ```
+    pub fn total_cmp(&self, other: &Self) -> Ordering {
+        self.cmp(other).then_with(|| self.build.cmp(&other.build))
+    }
+
+    /// Parses any string-like `s` as a [`Version`].
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if `s` is not a valid version.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use samurai::Version;
+    ///
+    /// let version = Version::parse(String::from("1.5.7"))
+    ///     .expect("`1.5.7` should be a valid version");
+    ///
+    /// assert!(version == Version::new(1, 5, 7));
+    /// ```
+    pub fn parse<S: AsRef<str>>(s: S) -> Result<Self> {
+        s.as_ref().parse()
+    }
+
+    /// Parses the UTF-8 encoded `bytes` as a [`Version`].
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if `bytes` is not valid UTF-8 or not a valid version.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use samurai::Version;
+    ///
+    /// let version = Version::from_bytes(b"1.5.7").expect("`1.5.7` should be a valid version");
+    ///
+    /// assert!(version == Version::new(1, 5, 7));
+    /// ```
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        str::from_utf8(bytes)
+            .map_err(ParseError::InvalidUtf8)?
+            .parse()
+    }
+
+    /// Parses `s` as a [`Version`] consisting of at most `max` parts.
+    ///
+    /// Leading and trailing ASCII whitespace is ignored. Missing parts default to `0` and only the
+    /// first three parts are kept, any part after the patch is validated and then ignored. Build
+    /// metadata may follow a `+`.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if a part cannot be parsed as `u32`, if `s` has more
+    /// than `max` parts or if the build metadata is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use samurai::Version;
+    ///
+    /// let version = Version::parse_max_parts("1.5.7.9", 4).expect("`1.5.7.9` should be valid");
+    ///
+    /// assert_eq!(version.major, 1);
+    /// assert_eq!(version.minor, 5);
+    /// assert_eq!(version.patch, 7);
+    /// ```
+    pub fn parse_max_parts(s: &str, max: usize) -> Result<Self> {
+        let s = s.trim_matches(|ch: char| ch.is_ascii_whitespace());
+        if s.is_empty() {
+            return Err(ParseError::EmptyInput);
+        }
+
+        let (s, build) = match s.split_once('+') {
+            // A `+` at the start of a part is a sign rather than the start of build metadata.
+            Some((s, build)) if !s.is_empty() && !s.ends_with('.') => (s, parse_build(build)?),
+            _ => (s, Vec::new()),
+        };
+
+        let parts: Vec<_> = s
+            .split('.')
+            .map(|part| {
+                if part.is_empty() {
+                    return Err(ParseError::EmptyPart(s.to_string()));
+                }
+
+                parse_part(part)
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        if parts.len() > max {
+            return Err(ParseError::TooManyParts);
+        }
+
+        let major = parts
+            .first()
+            .expect("should be available due to previous parsing");
+        let minor = parts.get(1).unwrap_or(&0);
+        let patch = parts.get(2).unwrap_or(&0);
+
+        Ok(Self {
+            parts: parts.len().min(3),
+            build,
+            ..Self::new(*major, *minor, *patch)
+        })
+    }
+
+    /// Parses `s` as a [`Version`] accepting any number of parts.
+    ///
+    /// This is lossy: every part after the patch (e.g. the revision of Windows-style `1.2.3.4`
+    /// versions) is validated and then dropped. Use [`FromStr`] for strict parsing.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if a part cannot be parsed as `u32`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use samurai::Version;
+    ///
+    /// let version = Version::parse_lenient("1.2.3.4")
+    ///     .expect("`1.2.3.4` should be a valid version");
+    ///
+    /// assert!(version == Version::new(1, 2, 3));
+    /// ```
+    pub fn parse_lenient(s: &str) -> Result<Self> {
+        Self::parse_max_parts(s, usize::MAX)
+    }
+
+    /// Parses `s` as a [`Version`] and returns how many parts it was given with.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if `s` is not a valid version.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use samurai::{version::Precision, Version};
+    ///
+    /// let (version, precision) =
+    ///     Version::parse_with_precision("1").expect("`1` should be a valid version");
+    ///
+    /// assert!(version == Version::new(1, 0, 0));
+    /// assert_eq!(precision, Precision::Major);
+    /// ```
+    pub fn parse_with_precision(s: &str) -> Result<(Self, Precision)> {
+        let version = s.parse::<Self>()?;
+        let precision = version.precision();
+
+        Ok((version, precision))
+    }
+
+    /// Parses the `CARGO_PKG_VERSION` environment variable as a [`Version`].
+    ///
+    /// The variable is read at runtime, so it has to be present in the environment of the
+    /// process, as it is for build scripts and `cargo run`. To embed the version of the crate
+    /// being compiled instead, parse `env!("CARGO_PKG_VERSION")`.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the variable is not set or is not a valid version.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use samurai::Version;
+    ///
+    /// let version = Version::from_cargo_env().expect("`CARGO_PKG_VERSION` should be set");
+    ///
+    /// assert!(version == env!("CARGO_PKG_VERSION"));
+    /// ```
+    pub fn from_cargo_env() -> Result<Self> {
+        const NAME: &str = "CARGO_PKG_VERSION";
+
+        env::var(NAME)
+            .map_err(|error| ParseError::MissingEnvVar {
+                name: NAME.to_string(),
+                error,
+            })?
+            .parse()
+    }
+
+    /// Parses the output of `git describe --tags`, e.g. `v1.2.3-5-gabc1234`.
+    ///
+    /// Returns the [`Version`] of the tag, the number of commits since the tag and the abbreviated
+    /// commit hash. A leading `v` is stripped, and a bare tag yields a count of `0` and an empty
+    /// hash.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the tag is not a valid version or the commit count
+    /// cannot be parsed as `u32`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use samurai::Version;
+    ///
+    /// let (version, count, hash) = Version::parse_git_describe("v1.2.3-5-gabc1234")
+    ///     .expect("`v1.2.3-5-gabc1234` should be a valid description");
+    ///
+    /// assert!(version == Version::new(1, 2, 3));
+    /// assert_eq!((count, hash.as_str()), (5, "abc1234"));
+    /// ```
+    pub fn parse_git_describe(s: &str) -> Result<(Self, u32, String)> {
+        let s = s.trim_matches(|ch: char| ch.is_ascii_whitespace());
+        let s = s.strip_prefix('v').unwrap_or(s);
+
+        let mut parts = s.rsplitn(3, '-');
+        if let (Some(hash), Some(count), Some(tag)) = (parts.next(), parts.next(), parts.next()) {
+            if let Some(hash) = hash.strip_prefix('g').filter(|hash| !hash.is_empty()) {
+                return Ok((tag.parse()?, parse_part(count)?, hash.to_string()));
+            }
+        }
+
+        Ok((s.parse()?, 0, String::new()))
+    }
+
+    /// Checks whether instance of [`Version`] satisfies `pattern`.
+    ///
+    /// You can find a cheat-sheet of patterns [here](https://devhints.io/semver). A pattern without
+    /// an operator (e.g. `1.2.9`) is treated as a caret requirement, just like npm and Cargo do,
+    /// while `*` or an empty pattern is satisfied by any version. A partial version without an
+    /// operator is satisfied by any value of its missing parts, e.g. `1.2` means `>=1.2.0, <1.3.0`.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if it cannot detect a valid pattern.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use samurai::Version;
+    ///
+    /// let version = "1.5.7".parse::<Version>().expect("`1.5.7` should be a valid version");
+    ///
+    /// assert!(version.satisfies("^1.2.9").expect("`^1.2.9` should be a valid pattern"));
+    /// assert!(version.satisfies("~1.5.4").expect("`~1.5.4` should be a valid pattern"));
+    /// ```
+    pub fn satisfies(&self, pattern: &str) -> Result<bool> {
+        Ok(pattern.parse::<VersionReq>()?.matches(self))
+    }
+
+    /// Explains why instance of [`Version`] does not satisfy `pattern`.
+    ///
+    /// The inner result is [`Ok`] if `pattern` is satisfied and otherwise carries the reason.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if it cannot detect a valid pattern.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use samurai::Version;
+    ///
+    /// let version = Version::new(1, 2, 3);
+    /// let outcome = version.explain(">=2.0.0").expect("`>=2.0.0` should be a valid pattern");
+    ///
+    /// assert_eq!(outcome, Err("1.2.3 is below the lower bound 2.0.0".to_string()));
+    /// ```
+    pub fn explain(&self, pattern: &str) -> Result<result::Result<(), String>> {
+        Ok(pattern.parse::<VersionReq>()?.explain(self))
+    }
+
+    /// Checks whether instance of [`Version`] satisfies every pattern of `patterns`.
+    ///
+    /// Stops at the first pattern that is not satisfied, so later patterns are not validated.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if it cannot detect a valid pattern.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use samurai::Version;
+    ///
+    /// let version = Version::new(1, 5, 7);
+    ///
+    /// assert!(version.satisfies_all(&["^1.2.9", "<1.6.0"]).expect("patterns should be valid"));
+    /// ```
+    pub fn satisfies_all(&self, patterns: &[&str]) -> Result<bool> {
+        for pattern in patterns {
+            if !self.satisfies(pattern)? {
+                return Ok(false);
+            }
+        }
+
+        Ok(true)
+    }
+
+    /// Checks instance of [`Version`] against `pattern`.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if it cannot detect a valid pattern.
+    #[deprecated(note = "use `Version::satisfies` instead")]
+    pub fn check(&self, pattern: &str) -> Result<bool> {
+        self.satisfies(pattern)
+    }
+}
+
+impl FromStr for Version {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> result::Result<Self, Self::Err> {
+        match parse_fast(s) {
+            Some(version) => Ok(version),
+            None => Self::parse_max_parts(s, 3),
+        }
+    }
+}
+
+impl fmt::Display for Version {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)?;
+
+        if !self.build.is_empty() {
+            write!(f, "+{}", self.build.join("."))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Debug for Version {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Version")
+            .field("major", &self.major)
+            .field("minor", &self.minor)
+            .field("patch", &self.patch)
+            .finish_non_exhaustive()
+    }
+}
+
+impl PartialEq for Version {
+    fn eq(&self, other: &Self) -> bool {
+        self.major == other.major && self.minor == other.minor && self.patch == other.patch
+    }
+}
+
+/// Parses the right-hand side before comparing, a malformed string compares unequal.
+impl PartialEq<str> for Version {
+    fn eq(&self, other: &str) -> bool {
+        other.parse::<Self>().is_ok_and(|other| *self == other)
+    }
+}
+
+/// Parses the right-hand side before comparing, a malformed string compares unequal.
+impl PartialEq<&str> for Version {
+    fn eq(&self, other: &&str) -> bool {
+        *self == **other
+    }
+}
+
+/// Parses the right-hand side before comparing, a malformed string is unordered so that every
+/// comparison operator returns `false`.
+impl PartialOrd<str> for Version {
+    fn partial_cmp(&self, other: &str) -> Option<Ordering> {
+        other.parse::<Self>().ok().map(|other| self.cmp(&other))
+    }
+}
+
+/// Parses the right-hand side before comparing, a malformed string is unordered so that every
+/// comparison operator returns `false`.
+impl PartialOrd<&str> for Version {
+    fn partial_cmp(&self, other: &&str) -> Option<Ordering> {
+        self.partial_cmp(*other)
+    }
+}
+
+impl PartialOrd for Version {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Version {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.major, self.minor, self.patch).cmp(&(other.major, other.minor, other.patch))
+    }
+}
+
+impl From<(u32, u32, u32)> for Version {
+    fn from((major, minor, patch): (u32, u32, u32)) -> Self {
+        Self::new(major, minor, patch)
+    }
+}
+
+/// A builder for assembling a [`Version`] part by part.
+///
+/// Parts that are not set default to `0`.
+///
+/// # Examples
+///
+/// ```
+/// use samurai::version::VersionBuilder;
+///
+/// let version = VersionBuilder::new().major(1).patch(3).build();
+///
+/// assert_eq!(version.to_string(), "1.0.3");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct VersionBuilder {
+    major: u32,
+    minor: u32,
+    patch: u32,
+    build: Vec<String>,
+}
+
+impl VersionBuilder {
+    /// Creates a new [`VersionBuilder`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the major part.
+    pub fn major(mut self, major: u32) -> Self {
+        self.major = major;
+        self
+    }
+
+    /// Sets the minor part.
+    pub fn minor(mut self, minor: u32) -> Self {
+        self.minor = minor;
+        self
+    }
+
+    /// Sets the patch part.
+    pub fn patch(mut self, patch: u32) -> Self {
+        self.patch = patch;
+        self
+    }
+
+    /// Sets the build metadata from its dot-separated identifiers, e.g. `build.7`.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if `build` is not valid build metadata.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use samurai::version::VersionBuilder;
+    ///
+    /// let version = VersionBuilder::new()
+    ///     .major(1)
+    ///     .build_metadata("build.7")
+    ///     .expect("`build.7` should be valid build metadata")
+    ///     .build();
+    ///
+    /// assert_eq!(version.to_string(), "1.0.0+build.7");
+    /// ```
+    pub fn build_metadata(mut self, build: &str) -> Result<Self> {
+        self.build = parse_build(build)?;
+        Ok(self)
+    }
+
+    /// Builds the [`Version`].
+    pub fn build(&self) -> Version {
+        Version {
+            build: self.build.clone(),
+            ..Version::new(self.major, self.minor, self.patch)
+        }
+    }
+}
+
+/// A [`Version`] with `u64` parts, for schemes whose counters may outgrow `u32`.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct WideVersion {
+    /// Represents incompatible API changes.
+    pub major: u64,
+
+    /// Represents functionality additions in a backwards compatible manner.
+    pub minor: u64,
+
+    /// Represents bug fixes in a backwards compatible manner.
+    pub patch: u64,
+}
+
+impl WideVersion {
+    /// Creates a new [`WideVersion`].
+    pub fn new(major: u64, minor: u64, patch: u64) -> Self {
+        Self {
+            major,
+            minor,
+            patch,
+        }
+    }
+
+    /// Returns instance of [`WideVersion`] with its patch part incremented.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use samurai::Version;
+    ///
+    /// let version = Version::new(1, 5, u32::MAX).to_wide().bump_patch();
+    ///
+    /// assert_eq!(version.patch, u32::MAX as u64 + 1);
+    /// ```
+    pub fn bump_patch(&self) -> Self {
+        Self::new(self.major, self.minor, self.patch + 1)
+    }
+}
+
+impl From<Version> for WideVersion {
+    fn from(version: Version) -> Self {
+        Self::new(
+            version.major.into(),
+            version.minor.into(),
+            version.patch.into(),
+        )
+    }
+}
+
+/// A [`Version`] that is known to be a valid `YYYY.MM.DD` date.
+///
+/// # Examples
+///
+/// ```
+/// use samurai::{version::CalVer, Version};
+///
+/// let calver = CalVer::from_version(&Version::new(2024, 1, 15))
+///     .expect("`2024.1.15` should be a valid date");
+///
+/// assert_eq!(calver.year(), 2024);
+/// assert!(CalVer::from_version(&Version::new(2024, 13, 1)).is_none());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct CalVer(Version);
+
+impl CalVer {
+    /// Creates a new [`CalVer`], returning [`None`] if `version` is not a valid date.
+    ///
+    /// See [`Version::as_calver_date`] for the accepted parts.
+    pub fn from_version(version: &Version) -> Option<Self> {
+        version.as_calver_date().map(|_| Self(version.clone()))
+    }
+
+    /// Returns the year, i.e. the major part.
+    pub fn year(&self) -> u32 {
+        self.0.major
+    }
+
+    /// Returns the month, i.e. the minor part.
+    pub fn month(&self) -> u32 {
+        self.0.minor
+    }
+
+    /// Returns the day, i.e. the patch part.
+    pub fn day(&self) -> u32 {
+        self.0.patch
+    }
+
+    /// Returns the underlying [`Version`].
+    pub fn as_version(&self) -> &Version {
+        &self.0
+    }
+}
+
+impl fmt::Display for CalVer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// Parses the common `X.Y.Z` case without allocating.
+///
+/// Returns [`None`] for anything else, including malformed input, so that the general path can
+/// produce the error.
+fn parse_fast(s: &str) -> Option<Version> {
+    let mut parts = s.split('.').map(|part| {
+        if part.is_empty() || !part.bytes().all(|byte| byte.is_ascii_digit()) {
+            return None;
+        }
+
+        part.parse().ok()
+    });
+
+    let major = parts.next()??;
+    let minor = parts.next()??;
+    let patch = parts.next()??;
+
+    if parts.next().is_some() {
+        return None;
+    }
+
+    Some(Version::new(major, minor, patch))
+}
+
+/// Parses the dot-separated identifiers of build metadata, i.e. what follows the `+`.
+fn parse_build(build: &str) -> Result<Vec<String>> {
+    build
+        .split('.')
+        .map(|identifier| {
+            let is_valid = !identifier.is_empty()
+                && identifier
+                    .bytes()
+                    .all(|byte| byte.is_ascii_alphanumeric() || byte == b'-');
+
+            if !is_valid {
+                return Err(ParseError::InvalidBuild(build.to_string()));
+            }
+
+            Ok(identifier.to_string())
+        })
+        .collect()
+}
+
+pub(crate) fn parse_part(part: &str) -> Result<u32> {
+    // `u32::from_str` accepts a leading `+`, which is not valid in a version.
+    if part.starts_with(['+', '-']) {
+        return Err(ParseError::SignedPart(part.to_string()));
+    }
+
+    part.parse().map_err(|err: ParseIntError| match err.kind() {
+        IntErrorKind::PosOverflow => ParseError::OutOfRange(part.to_string()),
+        _ => ParseError::InvalidInteger {
+            part: part.to_string(),
+            error: err,
+        },
+    })
+}
+
+/// Extracts every `[X.Y.Z]`-bracketed [`Version`] from `text` in document order.
+///
+/// This is meant for changelog headers like `## [1.2.3] - 2024-01-01`, brackets that do not
+/// contain a full `X.Y.Z` version (e.g. footnotes like `[1]`) are skipped.
+///
+/// # Examples
+///
+/// ```
+/// use samurai::{version, Version};
+///
+/// let versions = version::scan_versions("## [Unreleased]\n## [1.2.3] - 2024-01-01");
+///
+/// assert!(versions == [Version::new(1, 2, 3)]);
+/// ```
+pub fn scan_versions(text: &str) -> Vec<Version> {
+    text.split('[')
+        .skip(1)
+        .filter_map(|chunk| chunk.split_once(']'))
+        .filter_map(|(inside, _)| inside.parse::<Version>().ok())
+        .filter(|version| version.precision() == Precision::Full)
+        .collect()
+}
+
+/// Parses a comma-separated list of [`Version`]s, e.g. `1.0.0, 1.2.3, 2.0.0`.
+///
+/// # Errors
+///
+/// This function will return an error carrying the index of the first element that is not a
+/// valid version.
+///
+/// # Examples
+///
+/// ```
+/// use samurai::{version, Version};
+///
+/// let versions = version::parse_list("1.0.0, 1.2.3").expect("both elements should be valid");
+///
+/// assert!(versions == [Version::new(1, 0, 0), Version::new(1, 2, 3)]);
+/// ```
+pub fn parse_list(s: &str) -> Result<Vec<Version>> {
+    s.split(',')
+        .enumerate()
+        .map(|(index, element)| {
+            element.parse().map_err(|error| ParseError::ListElement {
+                index,
+                error: Box::new(error),
+            })
+        })
+        .collect()
+}
+
+/// Returns the first `X.Y.Z`-looking token of `text` as a [`Version`].
+///
+/// This is meant for noisy output like that of `tool --version`. A token is a run of digits and
+/// dots, so prefixes like `v1.2.3` are skipped over, while tokens with fewer or more than three
+/// parts (e.g. IP addresses) are ignored.
+///
+/// # Examples
+///
+/// ```
+/// use samurai::{version, Version};
+///
+/// let version = version::extract_first_version("tool 1.2.3 (abc1234 2024-01-01)");
+///
+/// assert!(version == Some(Version::new(1, 2, 3)));
+/// ```
+pub fn extract_first_version(text: &str) -> Option<Version> {
+    text.split(|ch: char| !ch.is_ascii_digit() && ch != '.')
+        .map(|token| token.trim_matches('.'))
+        .filter(|token| token.split('.').count() == 3)
+        .find_map(|token| token.parse().ok())
+}
+
+/// Parses each of `tokens` as a [`Version`] without stopping at the first failure.
+///
+/// The results are in the same order as `tokens`, so failures can be traced back to their input.
+///
+/// # Examples
+///
+/// ```
+/// use samurai::version;
+///
+/// let results = version::parse_many(&["1.0.0", "oops"]);
+///
+/// assert!(results[0].is_ok());
+/// assert!(results[1].is_err());
+/// ```
+pub fn parse_many(tokens: &[&str]) -> Vec<Result<Version>> {
+    tokens.iter().map(|token| token.parse()).collect()
+}
+
+/// Parses `a` and `b` as [`Version`]s and compares them.
+///
+/// # Errors
+///
+/// This function will return an error if either `a` or `b` is not a valid version.
+///
+/// # Examples
+///
+/// ```
+/// use samurai::version;
+/// use std::cmp::Ordering;
+///
+/// let ordering = version::compare("1.2.0", "1.10.0").expect("both should be valid versions");
+///
+/// assert_eq!(ordering, Ordering::Less);
+/// ```
+pub fn compare(a: &str, b: &str) -> Result<Ordering> {
+    Ok(a.parse::<Version>()?.cmp(&b.parse()?))
+}
+
+/// Parses each of `strings` as a [`Version`] and returns them sorted in ascending order.
+///
+/// # Errors
+///
+/// This function will return an error carrying the index of the first element that is not a
+/// valid version.
+///
+/// # Examples
+///
+/// ```
+/// use samurai::{version, Version};
+///
+/// match version::try_sort(&["1.10.0", "1.2.0"]) {
+///     Ok(versions) => assert!(versions == [Version::new(1, 2, 0), Version::new(1, 10, 0)]),
+///     Err(err) => panic!("both should be valid versions: {}", err),
+/// }
+/// ```
+pub fn try_sort(strings: &[&str]) -> Result<Vec<Version>> {
+    let mut versions = strings
+        .iter()
+        .enumerate()
+        .map(|(index, s)| {
+            s.parse().map_err(|error| ParseError::ListElement {
+                index,
+                error: Box::new(error),
+            })
+        })
+        .collect::<Result<Vec<Version>>>()?;
+
+    versions.sort();
+    Ok(versions)
+}
+
+/// Returns the index at which `version` should be inserted to keep `sorted` sorted.
+///
+/// This performs a binary search, so `sorted` must already be sorted in ascending order. Equal
+/// versions are inserted after the existing ones.
+///
+/// # Examples
+///
+/// ```
+/// use samurai::{version, Version};
+///
+/// let sorted = [Version::new(1, 0, 0), Version::new(1, 2, 0), Version::new(2, 0, 0)];
+///
+/// assert_eq!(version::insertion_index(&sorted, &Version::new(1, 5, 0)), 2);
+/// ```
+pub fn insertion_index(sorted: &[Version], version: &Version) -> usize {
+    sorted.partition_point(|other| other <= version)
+}
+
+/// Parses `data` as a [`Version`] and checks that it survives a round trip through [`Display`].
+///
+/// This is meant to be used as a fuzz target (e.g. with `libfuzzer-sys`), inputs that fail to
+/// parse are silently ignored.
+///
+/// # Panics
+///
+/// Panics if a successfully parsed version does not parse back into an equal [`Version`].
+///
+/// [`Display`]: fmt::Display
+#[cfg(feature = "fuzzing")]
+pub fn fuzz_parse(data: &[u8]) {
+    let Ok(version) = Version::from_bytes(data) else {
+        return;
+    };
+
+    let reparsed = version
+        .to_string()
+        .parse::<Version>()
+        .expect("displayed version should be parsable");
+
+    assert!(version == reparsed, "`{}` does not round-trip", version);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_in_const() {
+        const MIN_SUPPORTED: Version = Version::new(1, 2, 0);
+
+        assert_eq!(MIN_SUPPORTED.major, 1);
+        assert_eq!(MIN_SUPPORTED.minor, 2);
+        assert_eq!(MIN_SUPPORTED.patch, 0);
+    }
+
+    #[test]
+    fn associated_constants() {
+        assert!(Version::ZERO == Version::new(0, 0, 0));
+        assert!(Version::MIN == Version::ZERO);
+        assert!(Version::MAX > Version::new(999, 999, 999));
+        assert!(Version::MIN < Version::new(0, 0, 1));
+    }
+
+    #[test]
+    fn try_new_valid() -> result::Result<(), InvalidVersionError> {
+        let v = Version::try_new(0, 0, 1)?;
+        assert!(v == Version::new(0, 0, 1));
+
+        Ok(())
+    }
+
+    #[test]
+    fn try_new_invalid() {
+        assert_eq!(Version::try_new(0, 0, 0), Err(InvalidVersionError::Zero));
+        assert_eq!(
+            Version::try_new(1, 0, Version::RESERVED),
+            Err(InvalidVersionError::ReservedPart {
+                level: Increment::Patch
+            })
+        );
+        assert_eq!(
+            Version::try_new(Version::MAX.major, Version::MAX.minor, Version::MAX.patch),
+            Err(InvalidVersionError::ReservedPart {
+                level: Increment::Major
+            })
+        );
+    }
+
+    #[test]
+    fn invalid_version_error_display() {
+        let err = InvalidVersionError::ReservedPart {
+            level: Increment::Minor,
+        };
+
+        assert_eq!(err.to_string(), "minor part is reserved");
+        assert_eq!(
+            InvalidVersionError::Zero.to_string(),
+            "version cannot be `0.0.0`"
+        );
+    }
+
+    #[test]
+    fn from_string() -> Result<()> {
+        let v = "1.8.9".parse::<Version>()?;
+
+        assert_eq!(v.major, 1);
+        assert_eq!(v.minor, 8);
+        assert_eq!(v.patch, 9);
+
+        Ok(())
+    }
+
+    #[test]
+    fn parse_string_like() -> Result<()> {
+        let v1 = Version::parse("1.8.9")?;
+        let v2 = Version::parse(String::from("1.8.9"))?;
+
+        assert!(v1 == v2);
+        assert!(v1 == Version::new(1, 8, 9));
+
+        Ok(())
+    }
+
+    #[test]
+    fn from_bytes() -> Result<()> {
+        let v = Version::from_bytes(b"1.8.9")?;
+        assert!(v == Version::new(1, 8, 9));
+
+        Ok(())
+    }
+
+    #[test]
+    fn from_invalid_utf8_bytes() {
+        let err = Version::from_bytes(&[b'1', b'.', 0xff]);
+
+        assert!(matches!(err, Err(ParseError::InvalidUtf8(_))));
+    }
+
+    #[test]
+    fn from_less_parts() -> Result<()> {
+        let v1 = "10".parse::<Version>()?;
+
+        assert_eq!(v1.major, 10);
+        assert_eq!(v1.minor, 0);
+        assert_eq!(v1.patch, 0);
+
+        let v2 = "6.9".parse::<Version>()?;
+
+        assert_eq!(v2.major, 6);
+        assert_eq!(v2.minor, 9);
+        assert_eq!(v2.patch, 0);
+
+        Ok(())
+    }
+
+    #[test]
+    #[should_panic(expected = "too many parts")]
+    fn from_too_many_parts_panics() {
+        "1.5.7.9".parse::<Version>().unwrap();
+    }
+
+    #[test]
+    fn from_padded_string() -> Result<()> {
+        let v = " 1.2.3\t\n".parse::<Version>()?;
+
+        assert_eq!(v.major, 1);
+        assert_eq!(v.minor, 2);
+        assert_eq!(v.patch, 3);
+
+        Ok(())
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot parse")]
+    fn from_internally_spaced_string_panics() {
+        "1. 2.3".parse::<Version>().unwrap();
+    }
+
+    #[test]
+    fn from_four_parts_with_max_parts() -> Result<()> {
+        let v = Version::parse_max_parts("1.5.7.9", 4)?;
+
+        assert_eq!(v.major, 1);
+        assert_eq!(v.minor, 5);
+        assert_eq!(v.patch, 7);
+
+        Ok(())
+    }
+
+    #[test]
+    #[should_panic(expected = "too many parts")]
+    fn from_four_parts_with_three_max_parts_panics() {
+        Version::parse_max_parts("1.5.7.9", 3).unwrap();
+    }
+
+    #[test]
+    fn fast_path_matches_general_path() {
+        let inputs = [
+            "1.2.3",
+            "0.0.0",
+            "01.002.3",
+            "4294967295.0.1",
+            "4294967296.0.1",
+            "1.2",
+            "1.2.3.4",
+            " 1.2.3 ",
+            "1..3",
+            "1.+2.3",
+            "1.x.3",
+            "",
+        ];
+
+        for input in inputs {
+            let general = Version::parse_max_parts(input, 3);
+
+            if let Some(fast) = parse_fast(input) {
+                assert_eq!(
+                    fast.display_original(),
+                    general
+                        .as_ref()
+                        .map(Version::display_original)
+                        .unwrap_or_default()
+                );
+                assert_eq!(Ok(fast), general, "{:?}", input);
+            }
+
+            assert_eq!(input.parse::<Version>(), general, "{:?}", input);
+        }
+
+        assert!(parse_fast("1.2.3").is_some());
+        assert!(parse_fast("1.2").is_none());
+    }
+
+    #[test]
+    fn from_string_with_build() -> Result<()> {
+        let v = "1.2.3+build.7".parse::<Version>()?;
+
+        assert_eq!(v, Version::new(1, 2, 3));
+        assert_eq!(v.cmp(&Version::new(1, 2, 3)), Ordering::Equal);
+        assert_eq!(v.build(), ["build", "7"]);
+        assert_eq!(v.to_string(), "1.2.3+build.7");
+        assert_eq!(v.to_string().parse::<Version>()?.build(), v.build());
+
+        let v = "1.2+exp-sha.5114f85".parse::<Version>()?;
+        assert_eq!(v.build(), ["exp-sha", "5114f85"]);
+        assert_eq!(v.display_original(), "1.2+exp-sha.5114f85");
+
+        assert!(Version::new(1, 2, 3).build().is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn total_cmp() -> Result<()> {
+        let a = "1.0.0+001".parse::<Version>()?;
+        let b = "1.0.0+002".parse::<Version>()?;
+
+        assert_eq!(a.cmp(&b), Ordering::Equal);
+        assert_eq!(a.total_cmp(&b), Ordering::Less);
+        assert_eq!(b.total_cmp(&a), Ordering::Greater);
+        assert_eq!(Version::new(1, 0, 0).total_cmp(&a), Ordering::Less);
+        assert_eq!(a.total_cmp(&Version::new(1, 0, 1)), Ordering::Less);
+
+        Ok(())
+    }
+
+    #[test]
+    #[should_panic(expected = "`build..7` is not valid build metadata")]
+    fn from_string_with_empty_build_identifier_panics() {
+        "1.2.3+build..7".parse::<Version>().unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "`` is not valid build metadata")]
+    fn from_string_with_empty_build_panics() {
+        "1.2.3+".parse::<Version>().unwrap();
+    }
+
+    #[test]
+    fn from_lenient_string() -> Result<()> {
+        let v = Version::parse_lenient("1.2.3.4")?;
+        assert!(v == Version::new(1, 2, 3));
+
+        let v = Version::parse_lenient("1.2")?;
+        assert!(v == Version::new(1, 2, 0));
+
+        assert!("1.2.3.4".parse::<Version>().is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn parse_with_precision() -> Result<()> {
+        assert_eq!(Version::parse_with_precision("1")?.1, Precision::Major);
+        assert_eq!(Version::parse_with_precision("1.2")?.1, Precision::Minor);
+        assert_eq!(Version::parse_with_precision("1.2.3")?.1, Precision::Full);
+        assert_eq!(
+            Version::parse_lenient("1.2.3.4")?.precision(),
+            Precision::Full
+        );
+
+        let (v, _) = Version::parse_with_precision("1.2")?;
+        assert!(v == Version::new(1, 2, 0));
+
+        Ok(())
+    }
+
+    #[test]
+    fn from_cargo_env() -> Result<()> {
+        assert_eq!(Version::from_cargo_env()?, env!("CARGO_PKG_VERSION"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn missing_env_var_display() {
+        let err = ParseError::MissingEnvVar {
+            name: "CARGO_PKG_VERSION".to_string(),
+            error: env::VarError::NotPresent,
+        };
+        assert_eq!(
+            err.to_string(),
+            "environment variable `CARGO_PKG_VERSION` is not set"
+        );
+
+        let err = ParseError::MissingEnvVar {
+            name: "CARGO_PKG_VERSION".to_string(),
+            error: env::VarError::NotUnicode("1.2.3".into()),
+        };
+        assert_eq!(
+            err.to_string(),
+            "environment variable `CARGO_PKG_VERSION` is not valid unicode"
+        );
+    }
+
+    #[test]
+    fn git_describe_clean_tag() -> Result<()> {
+        let (v, count, hash) = Version::parse_git_describe("v1.2.3")?;
+
+        assert!(v == Version::new(1, 2, 3));
+        assert_eq!(count, 0);
+        assert!(hash.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn git_describe_with_commits() -> Result<()> {
+        let (v, count, hash) = Version::parse_git_describe("v1.2.3-5-gabc1234\n")?;
+
+        assert!(v == Version::new(1, 2, 3));
+        assert_eq!(count, 5);
+        assert_eq!(hash, "abc1234");
+
+        let (v, count, _) = Version::parse_git_describe("0.9.0-12-g0f3e")?;
+        assert!(v == Version::new(0, 9, 0));
+        assert_eq!(count, 12);
+
+        Ok(())
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot parse `five` as u32")]
+    fn git_describe_invalid_count_panics() {
+        Version::parse_git_describe("v1.2.3-five-gabc1234").unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "empty version string")]
+    fn from_empty_string_panics() {
+        "".parse::<Version>().unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "empty version string")]
+    fn from_blank_string_panics() {
+        " \t ".parse::<Version>().unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "`1.2.` has an empty part")]
+    fn from_trailing_dot_panics() {
+        "1.2.".parse::<Version>().unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "`.1.2` has an empty part")]
+    fn from_leading_dot_panics() {
+        ".1.2".parse::<Version>().unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "`1..2` has an empty part")]
+    fn from_doubled_dot_panics() {
+        "1..2".parse::<Version>().unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "`4294967296` is out of range for u32")]
+    fn from_out_of_range_panics() {
+        "1.4294967296.0".parse::<Version>().unwrap();
+    }
+
+    #[test]
+    fn from_u32_max() -> Result<()> {
+        let v = "4294967295".parse::<Version>()?;
+        assert_eq!(v.major, u32::MAX);
+
+        Ok(())
+    }
+
+    #[test]
+    #[should_panic(expected = "part `-1` must not have a sign")]
+    fn from_negative_part_panics() {
+        "-1.2.3".parse::<Version>().unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "part `+2` must not have a sign")]
+    fn from_positive_part_panics() {
+        "1.+2.3".parse::<Version>().unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot parse `0-rc` as u32")]
+    fn from_pre_release_panics() {
+        "1.0.0-rc.1".parse::<Version>().unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "as u32")]
+    fn from_non_version_panics() {
+        "hi.there".parse::<Version>().unwrap();
+    }
+
+    #[test]
+    fn display() {
+        let v = Version::new(1, 8, 9);
+
+        assert_eq!(v.to_string(), "1.8.9");
+    }
+
+    #[test]
+    fn normalize() -> Result<()> {
+        assert_eq!(Version::new(1, 8, 9).normalize(), "1.8.9");
+        assert_eq!(" 01.2 ".parse::<Version>()?.normalize(), "1.2.0");
+        assert_eq!(Version::parse_lenient("1.002.3.4")?.normalize(), "1.2.3");
+
+        Ok(())
+    }
+
+    #[test]
+    fn display_original_precision() -> Result<()> {
+        let v = "1.2".parse::<Version>()?;
+        assert_eq!(v.display_original(), "1.2");
+        assert_eq!(v.to_string(), "1.2.0");
+
+        let v = "10".parse::<Version>()?;
+        assert_eq!(v.display_original(), "10");
+        assert_eq!(v.to_string(), "10.0.0");
+
+        let v = Version::parse_lenient("1.2.3.4")?;
+        assert_eq!(v.display_original(), "1.2.3");
+
+        assert_eq!(Version::new(1, 2, 0).display_original(), "1.2.0");
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "fuzzing")]
+    fn fuzz_parse_known_inputs() {
+        let inputs: [&[u8]; 8] = [
+            b"1.8.9",
+            b"10",
+            b"6.9",
+            b"1.5.7.9",
+            b"",
+            b"hi.there",
+            b"4294967295.0.0",
+            &[0xff, 0xfe, 0x2e],
+        ];
+
+        for input in inputs {
+            fuzz_parse(input);
+        }
+    }
+
+    #[test]
+    fn scan_changelog() {
+        let changelog = "# Changelog
+
+## [Unreleased]
+
+## [1.10.0] - 2024-03-01
+### Added
+- Support for [links](https://example.com).
+
+## [1.9.2] - 2024-01-15
+
+## [not.a.version] - 2023-12-24
+
+## [0.1.0] - 2023-01-01
+";
+
+        let expected = [
+            Version::new(1, 10, 0),
+            Version::new(1, 9, 2),
+            Version::new(0, 1, 0),
+        ];
+
+        assert!(scan_versions(changelog) == expected);
+    }
+
+    #[test]
+    fn scan_versions_skips_partial() {
+        assert!(scan_versions("See [1] and [2.0]").is_empty());
+    }
+
+    #[test]
+    fn extract_first_version_from_output() {
+        let v = extract_first_version("mytool version 1.2.3, built 2024.01.15 from 10.0.0.1");
+        assert!(v == Some(Version::new(1, 2, 3)));
+
+        let v = extract_first_version("rustc v1.80.0-nightly (0.1)");
+        assert!(v == Some(Version::new(1, 80, 0)));
+    }
+
+    #[test]
+    fn extract_first_version_without_version() {
+        assert!(extract_first_version("mytool version unknown, built 2024-01-15").is_none());
+        assert!(extract_first_version("").is_none());
+    }
+
+    #[test]
+    fn build_version() {
+        let v = VersionBuilder::new().minor(2).patch(3).major(1).build();
+        assert!(v == Version::new(1, 2, 3));
+
+        let v = VersionBuilder::new().minor(2).build();
+        assert!(v == Version::new(0, 2, 0));
+    }
+
+    #[test]
+    fn build_version_with_build_metadata() -> Result<()> {
+        let v = VersionBuilder::new()
+            .major(1)
+            .build_metadata("exp-sha.5114f85")?
+            .build();
+
+        assert_eq!(v.build(), ["exp-sha", "5114f85"]);
+        assert_eq!(v.to_string(), "1.0.0+exp-sha.5114f85");
+        assert_eq!(
+            VersionBuilder::new().build_metadata("build..7").err(),
+            Some(ParseError::InvalidBuild("build..7".to_string()))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn wide_bump_patch_past_u32() {
+        let v = Version::new(1, 5, u32::MAX).to_wide();
+
+        assert_eq!(v.bump_patch(), WideVersion::new(1, 5, 1 << 32));
+        assert_eq!(v.bump_patch().bump_patch().patch, (1 << 32) + 1);
+    }
+
+    #[test]
+    fn wide_from_version() {
+        let v = WideVersion::from(Version::new(1, 8, 9));
+
+        assert_eq!(v, WideVersion::new(1, 8, 9));
+    }
+
+    #[test]
+    fn calver_valid_date() -> Result<()> {
+        let v = Version::parse("2024.1.15")?;
+        let calver = CalVer::from_version(&v).expect("`2024.1.15` should be a valid date");
+
+        assert_eq!(v.as_calver_date(), Some((2024, 1, 15)));
+        assert_eq!((calver.year(), calver.month(), calver.day()), (2024, 1, 15));
+        assert_eq!(calver.as_version(), &v);
+        assert_eq!(calver.to_string(), "2024.1.15");
+
+        Ok(())
+    }
+
+    #[test]
+    fn calver_invalid_date() -> Result<()> {
+        let v = Version::parse("2024.13.1")?;
+
+        assert_eq!(v.as_calver_date(), None);
+        assert!(CalVer::from_version(&v).is_none());
+
+        for v in ["2024.0.1", "2024.1.0", "2024.1.32"] {
+            assert!(CalVer::from_version(&Version::parse(v)?).is_none());
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn to_tuple() {
+        assert_eq!(Version::new(1, 2, 3).to_tuple(), (1, 2, 3));
+    }
+
+    #[test]
+    fn from_tuple() {
+        let v = Version::new(1, 2, 3);
+
+        assert_eq!(Version::from((1, 2, 3)), v);
+        assert_eq!(Version::from(v.to_tuple()), v);
+    }
+
+    #[test]
+    fn parse_valid_list() -> Result<()> {
+        let versions = parse_list("1.0.0,1.2.3, 2.0.0 ")?;
+        let expected = [
+            Version::new(1, 0, 0),
+            Version::new(1, 2, 3),
+            Version::new(2, 0, 0),
+        ];
+
+        assert!(versions == expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn parse_list_with_malformed_element() {
+        let err = parse_list("1.0.0, 1.x.3, 2.0.0").err();
+
+        assert_eq!(
+            err,
+            Some(ParseError::ListElement {
+                index: 1,
+                error: Box::new(ParseError::InvalidInteger {
+                    part: "x".to_string(),
+                    error: "x".parse::<u32>().unwrap_err(),
+                }),
+            })
+        );
+        assert_eq!(
+            err.map(|err| err.to_string()).as_deref(),
+            Some("element 1: cannot parse `x` as u32")
+        );
+    }
+
+    #[test]
+    fn invalid_integer_has_source() {
+        use std::error::Error;
+
+        let err = Version::parse("1.x.3").unwrap_err();
+        let source = err.source().map(|source| source.to_string());
+
+        assert_eq!(source, Some("invalid digit found in string".to_string()));
+        assert!(Version::parse("1..3").unwrap_err().source().is_none());
+    }
+
+    #[test]
+    fn parse_many_keeps_failures() {
+        let results = parse_many(&["1.0.0", "oops", "2.1.0"]);
+
+        assert_eq!(
+            results,
+            [
+                Ok(Version::new(1, 0, 0)),
+                Err(ParseError::InvalidInteger {
+                    part: "oops".to_string(),
+                    error: "oops".parse::<u32>().unwrap_err(),
+                }),
+                Ok(Version::new(2, 1, 0)),
+            ]
+        );
+    }
+
+    #[test]
+    fn compare_numerically() -> Result<()> {
+        assert_eq!(compare("1.2.0", "1.10.0")?, Ordering::Less);
+        assert_eq!(compare("1.10.0", "1.9.9")?, Ordering::Greater);
+        assert_eq!(compare("1.2", "1.2.0")?, Ordering::Equal);
+
+        Ok(())
+    }
+
+    #[test]
+    #[should_panic(expected = "as u32")]
+    fn compare_invalid_panics() {
+        compare("1.2.0", "one.two").unwrap();
+    }
+
+    #[test]
+    fn try_sort_numeric_order() -> Result<()> {
+        let versions = try_sort(&["1.10.0", "1.2.0", "1.9.0"])?;
+
+        assert_eq!(versions, ["1.2.0", "1.9.0", "1.10.0"]);
+
+        Ok(())
+    }
+
+    #[test]
+    #[should_panic(expected = "element 1: cannot parse `x` as u32")]
+    fn try_sort_invalid_panics() {
+        try_sort(&["1.2.0", "1.x.0", "2.0"]).unwrap();
+    }
+
+    #[test]
+    fn insertion_index_in_middle() {
+        let sorted = [
+            Version::new(0, 9, 0),
+            Version::new(1, 0, 0),
+            Version::new(1, 2, 0),
+            Version::new(2, 0, 0),
+        ];
+
+        assert_eq!(insertion_index(&sorted, &Version::new(1, 1, 5)), 2);
+        assert_eq!(insertion_index(&sorted, &Version::new(1, 2, 0)), 3);
+    }
+
+    #[test]
+    fn insertion_index_at_start() {
+        let sorted = [Version::new(1, 0, 0), Version::new(2, 0, 0)];
+
+        assert_eq!(insertion_index(&sorted, &Version::new(0, 1, 0)), 0);
+        assert_eq!(insertion_index(&[], &Version::new(0, 1, 0)), 0);
+    }
+
+    #[test]
+    fn insertion_index_at_end() {
+        let sorted = [Version::new(1, 0, 0), Version::new(2, 0, 0)];
+
+        assert_eq!(insertion_index(&sorted, &Version::new(2, 0, 1)), 2);
+    }
+
+    #[test]
+    fn eq_str() {
+        let v = Version::new(1, 2, 3);
+
+        assert_eq!(v, "1.2.3");
+        assert_eq!(v, *"1.2.3");
+        assert_ne!(v, "1.2.4");
+        assert_ne!(v, "1.2.x");
+        assert_eq!(Version::new(1, 2, 0), "1.2");
+    }
+
+    #[test]
+    fn ord_two_instances() {
+        let v1 = Version::new(7, 8, 9);
+        let v2 = Version::new(8, 5, 8);
+        assert!(v1 < v2);
+
+        let v1 = Version::new(5, 2, 8);
+        let v2 = Version::new(5, 1, 9);
+        assert!(v1 > v2);
+        assert!(v1 >= v2);
+
+        let v1 = Version::new(1, 2, 7);
+        let v2 = Version::new(1, 2, 5);
+        assert!(v1 > v2);
+
+        let v1 = Version::new(6, 9, 9);
+        let v2 = Version::new(6, 9, 9);
+        assert!(v1 == v2);
+
+        let v1 = Version::new(8, 1, 20);
+        let v2 = Version::new(8, 81, 20);
+        assert!(v1 != v2);
+    }
+
+    #[test]
+    fn ord_against_str() {
+        let v = Version::new(1, 5, 7);
+
+        assert!(v < "2.0.0");
+        assert!(v > "1.5.6");
+        assert!(v <= "1.5.7");
+        assert!(v >= "1.5.7");
+        assert_eq!(v.partial_cmp("1.10.0"), Some(Ordering::Less));
+    }
+
+    #[test]
+    fn ord_against_malformed_str() {
+        let v = Version::new(1, 5, 7);
+
+        assert_eq!(v.partial_cmp("one.two"), None);
+        for unordered in [v < "one.two", v > "one.two", v <= "one.two", v >= "one.two"] {
+            assert!(!unordered);
+        }
+    }
+
+    #[test]
+    fn sort_shuffled() {
+        let mut versions = vec![
+            Version::new(1, 2, 0),
+            Version::new(1, 0, 0),
+            Version::new(0, 9, 0),
+            Version::new(1, 10, 0),
+            Version::new(1, 0, 1),
+        ];
+
+        versions.sort();
+        assert_eq!(versions, ["0.9.0", "1.0.0", "1.0.1", "1.2.0", "1.10.0"]);
+
+        versions.sort_by(|a, b| b.cmp(a));
+        assert_eq!(versions, ["1.10.0", "1.2.0", "1.0.1", "1.0.0", "0.9.0"]);
+    }
+
+    #[test]
+    fn ord_greater_patch() {
+        let v1 = Version::new(4, 2, 10);
+        let v2 = Version::new(4, 2, 9);
+
+        assert_eq!(v1.cmp(&v2), Ordering::Greater);
+        assert!(v1 > v2);
+    }
+
+    #[test]
+    fn ord_lower_patch() {
+        let v1 = Version::new(4, 2, 9);
+        let v2 = Version::new(4, 2, 10);
+
+        assert_eq!(v1.cmp(&v2), Ordering::Less);
+        assert!(v1 < v2);
+    }
+
+    #[test]
+    fn ord_consistent_for_all_pairs() -> Result<()> {
+        let versions = [
+            Version::new(0, 0, 0),
+            Version::new(0, 0, 1),
+            Version::new(0, 1, 0),
+            Version::new(1, 2, 3),
+            Version::new(1, 2, 4),
+            Version::new(1, 3, 0),
+            Version::new(2, 0, 0),
+            Version::MAX,
+            Version::parse("1.2")?,
+            Version::parse("1.2.0")?,
+            Version::parse_lenient("1.2.3.4")?,
+        ];
+
+        for a in &versions {
+            for b in &versions {
+                assert_eq!(a.cmp(b), b.cmp(a).reverse(), "{} and {}", a, b);
+                assert_eq!(a.cmp(b) == Ordering::Equal, a == b, "{} and {}", a, b);
+                assert_eq!(a.partial_cmp(b), Some(a.cmp(b)), "{} and {}", a, b);
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn diff_major() {
+        let v = Version::new(1, 2, 3);
+
+        assert_eq!(v.diff(&Version::new(2, 2, 3)), Some(Increment::Major));
+        assert_eq!(v.diff(&Version::new(0, 9, 9)), Some(Increment::Major));
+    }
+
+    #[test]
+    fn diff_minor() {
+        let v = Version::new(1, 2, 3);
+
+        assert_eq!(v.diff(&Version::new(1, 3, 0)), Some(Increment::Minor));
+    }
+
+    #[test]
+    fn diff_patch() {
+        let v = Version::new(1, 2, 3);
+
+        assert_eq!(v.diff(&Version::new(1, 2, 1)), Some(Increment::Patch));
+    }
+
+    #[test]
+    fn diff_identical() {
+        let v = Version::new(1, 2, 3);
+
+        assert_eq!(v.diff(&Version::new(1, 2, 3)), None);
+    }
+
+    #[test]
+    fn minor_delta_on_same_major() {
+        let v = Version::new(3, 4, 0);
+
+        assert_eq!(v.minor_delta(&Version::new(3, 1, 9)), Some(3));
+        assert_eq!(v.minor_delta(&Version::new(3, 10, 0)), Some(-6));
+        assert_eq!(v.minor_delta(&Version::new(3, 4, 7)), Some(0));
+    }
+
+    #[test]
+    fn minor_delta_across_majors() {
+        let v = Version::new(3, 4, 0);
+
+        assert_eq!(v.minor_delta(&Version::new(4, 4, 0)), None);
+    }
+
+    #[test]
+    fn patch_delta_on_same_minor() {
+        let v = Version::new(3, 4, 0);
+
+        assert_eq!(v.patch_delta(&Version::new(3, 4, 5)), Some(-5));
+        assert_eq!(
+            Version::new(0, 0, u32::MAX).patch_delta(&Version::ZERO),
+            Some(i64::from(u32::MAX))
+        );
+    }
+
+    #[test]
+    fn patch_delta_across_lines() {
+        let v = Version::new(3, 4, 0);
+
+        assert_eq!(v.patch_delta(&Version::new(3, 5, 0)), None);
+        assert_eq!(v.patch_delta(&Version::new(2, 4, 0)), None);
+    }
+
+    #[test]
+    fn cmp_at_major_level() {
+        let v = Version::new(3, 2, 1);
+
+        assert_eq!(
+            v.cmp_at_level(&Version::new(3, 9, 9), Increment::Major),
+            Ordering::Equal
+        );
+        assert_eq!(
+            v.cmp_at_level(&Version::new(2, 9, 9), Increment::Major),
+            Ordering::Greater
+        );
+        assert_eq!(
+            v.cmp_at_level(&Version::new(4, 0, 0), Increment::Major),
+            Ordering::Less
+        );
+    }
+
+    #[test]
+    fn cmp_at_minor_level() {
+        let v = Version::new(3, 2, 1);
+
+        assert_eq!(
+            v.cmp_at_level(&Version::new(3, 2, 9), Increment::Minor),
+            Ordering::Equal
+        );
+        assert_eq!(
+            v.cmp_at_level(&Version::new(3, 1, 9), Increment::Minor),
+            Ordering::Greater
+        );
+        assert_eq!(
+            v.cmp_at_level(&Version::new(3, 3, 0), Increment::Minor),
+            Ordering::Less
+        );
+    }
+
+    #[test]
+    fn cmp_at_patch_level() {
+        let v = Version::new(3, 2, 1);
+
+        assert_eq!(
+            v.cmp_at_level(&Version::new(3, 2, 1), Increment::Patch),
+            Ordering::Equal
+        );
+        assert_eq!(
+            v.cmp_at_level(&Version::new(3, 2, 0), Increment::Patch),
+            Ordering::Greater
+        );
+        assert_eq!(
+            v.cmp_at_level(&Version::new(3, 2, 2), Increment::Patch),
+            Ordering::Less
+        );
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn check_against_pattern() -> Result<()> {
+        let v = "7.8.9".parse::<Version>()?;
+        assert!(v.check("<8.5.8")?);
+
+        let v = "5.2.8".parse::<Version>()?;
+        assert!(v.check(">5.1.9")?);
+        assert!(v.check(">=5.1.9")?);
+
+        let v = "1.2.7".parse::<Version>()?;
+        assert!(v.check(">1.2.5")?);
+
+        let v = "6.9.9".parse::<Version>()?;
+        assert!(v.check("=6.9.9")?);
+
+        let v = "8.10.5".parse::<Version>()?;
+        assert!(v.check("^8.9.1")?);
+
+        let v = "30.11.21".parse::<Version>()?;
+        assert!(v.check("~30.11.20")?);
+
+        Ok(())
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn check_against_bare_pattern() -> Result<()> {
+        let v = Version::new(1, 5, 7);
+
+        assert_eq!(v.check("1.2.9")?, v.check("^1.2.9")?);
+        assert!(v.check("1.2.9")?);
+        assert!(!v.check("2.0.0")?);
+
+        Ok(())
     }
 
-    /// Checks instance of [`Version`] against `pattern`.
-    ///
-    /// You can find a cheat-sheet of patterns [here](https://devhints.io/semver).
-    ///
-    /// # Errors
-    ///
-    /// This function will return an error if it cannot detect a valid pattern.
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use samurai::Version;
-    ///
-    /// let version = "1.5.7".parse::<Version>().expect("`1.5.7` should be a valid version");
-    ///
-    /// assert!(version.check("^1.2.9").expect("`^1.2.9` should be a valid pattern"));
-    /// assert!(version.check("~1.5.4").expect("`~1.5.4` should be a valid pattern"));
-    /// ```
-    pub fn check(&self, pattern: &str) -> Result<bool> {
-        let Some(version_start) = pattern.find(|ch: char| ch.is_numeric()) else {
-            return Err("cannot extract the major part".to_string());
-        };
+    #[test]
+    #[allow(deprecated)]
+    fn check_against_partial_pattern() -> Result<()> {
+        assert!(Version::new(1, 5, 7).check("1")?);
+        assert!(!Version::new(2, 0, 0).check("1")?);
 
-        let operator = &pattern[..version_start];
-        let other = &pattern[version_start..].parse::<Self>()?;
+        assert!(Version::new(1, 2, 9).satisfies("1.2")?);
+        assert!(!Version::new(1, 3, 0).satisfies("1.2")?);
 
-        match operator {
-            "=" => Ok(self == other),
-            "<" => Ok(self < other),
-            ">" => Ok(self > other),
-            "<=" => Ok(self <= other),
-            ">=" => Ok(self >= other),
-            "^" => Ok(self.is_compatible(other)),
-            "~" => Ok(self.is_featureless(other)),
-            _ => Err(format!("operator `{}` not found", operator)),
-        }
+        Ok(())
     }
-}
 
-impl FromStr for Version {
-    type Err = String;
+    #[test]
+    #[allow(deprecated)]
+    fn check_against_inequality_pattern() -> Result<()> {
+        let v = Version::new(1, 2, 3);
 
-    fn from_str(s: &str) -> result::Result<Self, Self::Err> {
-        let parts: Vec<_> = s
-            .split('.')
-            .map(|part| {
-                part.parse()
-                    .map_err(|_| format!("cannot parse `{}` as u32", part))
-            })
-            .collect::<Result<Vec<_>>>()?;
+        assert!(v.check("!=1.2.4")?);
+        assert!(!v.check("!=1.2.3")?);
 
-        if parts.len() > 3 {
-            return Err("too many parts".to_string());
-        }
+        Ok(())
+    }
 
-        let major = parts
-            .first()
-            .expect("should be available due to previous parsing");
-        let minor = parts.get(1).unwrap_or(&0);
-        let patch = parts.get(2).unwrap_or(&0);
+    #[test]
+    #[allow(deprecated)]
+    #[should_panic(expected = "not found")]
+    fn check_against_invalid_pattern_panics() {
+        let v = Version::new(1, 0, 69);
+        v.check("seeya5.8.10").unwrap();
+    }
+
+    #[test]
+    fn satisfies_pattern() -> Result<()> {
+        let v = Version::new(1, 5, 7);
+
+        assert!(v.satisfies("^1.2.9")?);
+        assert!(v.satisfies("~1.5.4")?);
+        assert!(!v.satisfies(">=2.0.0")?);
 
-        Ok(Self::new(*major, *minor, *patch))
+        Ok(())
     }
-}
 
-impl PartialEq for Version {
-    fn eq(&self, other: &Self) -> bool {
-        self.major == other.major && self.minor == other.minor && self.patch == other.patch
+    #[test]
+    #[allow(deprecated)]
+    fn check_against_wildcard_pattern() -> Result<()> {
+        let v = Version::new(1, 5, 7);
+
+        assert!(v.check("*")?);
+        assert!(v.check("")?);
+
+        Ok(())
     }
-}
 
-impl PartialOrd for Version {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.cmp(other))
+    #[test]
+    fn explain_mismatch() -> Result<()> {
+        let v = Version::new(1, 2, 3);
+
+        assert_eq!(
+            v.explain("~2.0.0")?,
+            Err("1.2.3 is below the lower bound 2.0.0".to_string())
+        );
+        assert_eq!(
+            v.explain("~1.1.0")?,
+            Err("1.2.3 is not below the upper bound 1.2.0".to_string())
+        );
+        assert_eq!(v.explain("^1.0.0")?, Ok(()));
+
+        Ok(())
     }
-}
 
-impl Ord for Version {
-    fn cmp(&self, other: &Self) -> Ordering {
-        if self.major > other.major {
-            return Ordering::Greater;
-        }
+    #[test]
+    fn satisfies_all_patterns() -> Result<()> {
+        let v = Version::new(1, 5, 7);
 
-        if self.major == other.major {
-            if self.minor > other.minor {
-                return Ordering::Greater;
-            }
+        assert!(v.satisfies_all(&["^1.2.9", "~1.5.0"])?);
+        assert!(!v.satisfies_all(&["^1.2.9", ">=2.0.0"])?);
+        assert!(v.satisfies_all(&[])?);
 
-            if self.minor == other.minor {
-                if self.patch > other.patch {
-                    return Ordering::Greater;
-                }
+        Ok(())
+    }
 
-                if self.patch == other.patch {
-                    return Ordering::Equal;
-                }
-            }
-        }
+    #[test]
+    #[should_panic(expected = "not found")]
+    fn satisfies_all_with_invalid_pattern_panics() {
+        let v = Version::new(1, 5, 7);
+        v.satisfies_all(&["^1.2.9", "=>1.5.0"]).unwrap();
+    }
+
+    #[test]
+    fn satisfies_reversed_operator_errors() {
+        let v = Version::new(1, 2, 3);
 
-        Ordering::Less
+        assert_eq!(
+            v.satisfies("=>1.2.3"),
+            Err(ParseError::UnknownOperator("=>".to_string()))
+        );
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    #[test]
+    fn with_part() {
+        let v = Version::new(1, 2, 3);
+
+        assert_eq!(v.with_major(0), Version::new(0, 2, 3));
+        assert_eq!(v.with_minor(5), Version::new(1, 5, 3));
+        assert_eq!(v.with_patch(9), Version::new(1, 2, 9));
+        assert_eq!(v, Version::new(1, 2, 3));
+    }
 
     #[test]
-    fn from_string() -> Result<()> {
-        let v = "1.8.9".parse::<Version>()?;
+    fn with_part_sets_full_precision() -> Result<()> {
+        let v = "1.2".parse::<Version>()?;
 
-        assert_eq!(v.major, 1);
-        assert_eq!(v.minor, 8);
-        assert_eq!(v.patch, 9);
+        assert_eq!(v.with_patch(5).display_original(), "1.2.5");
+        assert_eq!(v.with_minor(5).display_original(), "1.5.0");
+        assert_eq!(v.with_major(5).display_original(), "5.2.0");
 
         Ok(())
     }
 
     #[test]
-    fn from_less_parts() -> Result<()> {
-        let v1 = "10".parse::<Version>()?;
-
-        assert_eq!(v1.major, 10);
-        assert_eq!(v1.minor, 0);
-        assert_eq!(v1.patch, 0);
+    fn release() -> Result<()> {
+        let v = "1.2.0+build.7".parse::<Version>()?.release();
+        assert!(v.build().is_empty());
+        assert_eq!(v.to_string(), "1.2.0");
 
-        let v2 = "6.9".parse::<Version>()?;
+        let v = "1.2+build.7".parse::<Version>()?.release();
+        assert_eq!(v.display_original(), "1.2");
 
-        assert_eq!(v2.major, 6);
-        assert_eq!(v2.minor, 9);
-        assert_eq!(v2.patch, 0);
+        let v = Version::new(1, 2, 0);
+        assert_eq!(v.release().to_string(), v.to_string());
 
         Ok(())
     }
 
     #[test]
-    #[should_panic(expected = "too many parts")]
-    fn from_too_many_parts_panics() {
-        "1.5.7.9".parse::<Version>().unwrap();
+    fn truncate() {
+        let v = Version::new(1, 2, 3);
+
+        assert_eq!(v.truncate_to_minor(), Version::new(1, 2, 0));
+        assert_eq!(v.truncate_to_major(), Version::new(1, 0, 0));
+        assert_eq!(
+            Version::new(1, 0, 0).truncate_to_major(),
+            Version::new(1, 0, 0)
+        );
     }
 
     #[test]
-    #[should_panic(expected = "cannot parse")]
-    fn from_empty_string_panics() {
-        "".parse::<Version>().unwrap();
+    fn checked_bump() {
+        let v = Version::new(1, 5, 7);
+
+        assert!(v.checked_bump_major() == Some(Version::new(2, 0, 0)));
+        assert!(v.checked_bump_minor() == Some(Version::new(1, 6, 0)));
+        assert!(v.checked_bump_patch() == Some(Version::new(1, 5, 8)));
     }
 
     #[test]
-    #[should_panic(expected = "as u32")]
-    fn from_non_version_panics() {
-        "hi.there".parse::<Version>().unwrap();
+    fn checked_bump_at_u32_max() {
+        let v = Version::new(u32::MAX, u32::MAX, u32::MAX);
+
+        assert!(v.checked_bump_major().is_none());
+        assert!(v.checked_bump_minor().is_none());
+        assert!(v.checked_bump_patch().is_none());
     }
 
     #[test]
-    fn ord_two_instances() {
-        let v1 = Version::new(7, 8, 9);
-        let v2 = Version::new(8, 5, 8);
-        assert!(v1 < v2);
+    fn predecessor_patch() {
+        assert!(Version::new(1, 2, 3).predecessor_patch() == Some(Version::new(1, 2, 2)));
+        assert!(Version::new(1, 2, 1).predecessor_patch() == Some(Version::new(1, 2, 0)));
+        assert!(Version::new(1, 2, 0).predecessor_patch().is_none());
+    }
 
-        let v1 = Version::new(5, 2, 8);
-        let v2 = Version::new(5, 1, 9);
-        assert!(v1 > v2);
-        assert!(v1 >= v2);
+    #[test]
+    fn saturating_bump() {
+        let v = Version::new(1, 5, 7);
 
-        let v1 = Version::new(1, 2, 7);
-        let v2 = Version::new(1, 2, 5);
-        assert!(v1 > v2);
+        assert!(v.saturating_bump_major() == Version::new(2, 0, 0));
+        assert!(v.saturating_bump_minor() == Version::new(1, 6, 0));
+        assert!(v.saturating_bump_patch() == Version::new(1, 5, 8));
+    }
 
-        let v1 = Version::new(6, 9, 9);
-        let v2 = Version::new(6, 9, 9);
-        assert!(v1 == v2);
+    #[test]
+    fn saturating_bump_at_u32_max() {
+        let v = Version::new(u32::MAX, 4, 2);
+        assert!(v.saturating_bump_major() == v);
 
-        let v1 = Version::new(8, 1, 20);
-        let v2 = Version::new(8, 81, 20);
-        assert!(v1 != v2);
+        let v = Version::new(1, u32::MAX, 2);
+        assert!(v.saturating_bump_minor() == v);
+
+        let v = Version::new(1, 4, u32::MAX);
+        assert!(v.saturating_bump_patch() == v);
     }
 
     #[test]
-    fn check_against_pattern() -> Result<()> {
-        let v = "7.8.9".parse::<Version>()?;
-        assert!(v.check("<8.5.8")?);
+    fn try_bump() {
+        let v = Version::new(1, 5, 7);
+
+        for policy in [
+            OverflowPolicy::Saturate,
+            OverflowPolicy::Error,
+            OverflowPolicy::Wrap,
+        ] {
+            assert_eq!(
+                v.try_bump(Increment::Major, policy),
+                Ok(Version::new(2, 0, 0))
+            );
+            assert_eq!(
+                v.try_bump(Increment::Minor, policy),
+                Ok(Version::new(1, 6, 0))
+            );
+            assert_eq!(
+                v.try_bump(Increment::Patch, policy),
+                Ok(Version::new(1, 5, 8))
+            );
+        }
+    }
 
-        let v = "5.2.8".parse::<Version>()?;
-        assert!(v.check(">5.1.9")?);
-        assert!(v.check(">=5.1.9")?);
+    #[test]
+    fn try_bump_saturate_at_u32_max() {
+        let v = Version::new(u32::MAX, u32::MAX, u32::MAX);
 
-        let v = "1.2.7".parse::<Version>()?;
-        assert!(v.check(">1.2.5")?);
+        for level in [Increment::Major, Increment::Minor, Increment::Patch] {
+            assert_eq!(v.try_bump(level, OverflowPolicy::Saturate), Ok(v.clone()));
+        }
+    }
 
-        let v = "6.9.9".parse::<Version>()?;
-        assert!(v.check("=6.9.9")?);
+    #[test]
+    fn try_bump_error_at_u32_max() {
+        let v = Version::new(u32::MAX, u32::MAX, u32::MAX);
+
+        for level in [Increment::Major, Increment::Minor, Increment::Patch] {
+            assert_eq!(
+                v.try_bump(level, OverflowPolicy::Error),
+                Err(OverflowError { level })
+            );
+        }
+    }
 
-        let v = "8.10.5".parse::<Version>()?;
-        assert!(v.check("^8.9.1")?);
+    #[test]
+    fn try_bump_wrap_at_u32_max() {
+        let v = Version::new(u32::MAX, u32::MAX, u32::MAX);
+
+        assert_eq!(
+            v.try_bump(Increment::Major, OverflowPolicy::Wrap),
+            Ok(Version::new(0, 0, 0))
+        );
+        assert_eq!(
+            v.try_bump(Increment::Minor, OverflowPolicy::Wrap),
+            Ok(Version::new(u32::MAX, 0, 0))
+        );
+        assert_eq!(
+            v.try_bump(Increment::Patch, OverflowPolicy::Wrap),
+            Ok(Version::new(u32::MAX, u32::MAX, 0))
+        );
+    }
 
-        let v = "30.11.21".parse::<Version>()?;
-        assert!(v.check("~30.11.20")?);
+    #[test]
+    fn bump_str() -> Result<()> {
+        let v = Version::new(1, 5, 7);
+
+        assert_eq!(v.bump_str("MAJOR")?, Version::new(2, 0, 0));
+        assert_eq!(v.bump_str("minor")?, Version::new(1, 6, 0));
+        assert_eq!(v.bump_str("patch")?, Version::new(1, 5, 8));
 
         Ok(())
     }
 
     #[test]
-    #[should_panic(expected = "not found")]
-    fn check_against_invalid_pattern_panics() {
-        let v = Version::new(1, 0, 69);
-        v.check("seeya5.8.10").unwrap();
+    #[should_panic(expected = "level `foo` not found")]
+    fn bump_str_with_unknown_level_panics() {
+        Version::new(1, 5, 7).bump_str("foo").unwrap();
+    }
+
+    #[test]
+    fn as_exact_req_matches_only_itself() {
+        let v = Version::new(1, 5, 7);
+        let req = v.as_exact_req();
+
+        assert!(req.is_exact());
+        assert!(req.matches(&v));
+        assert!(!req.matches(&Version::new(1, 5, 6)));
+        assert!(!req.matches(&Version::new(1, 5, 8)));
+        assert!(!req.matches(&Version::new(2, 0, 0)));
+    }
+
+    #[test]
+    fn is_initial_development() {
+        assert!(Version::new(0, 9, 0).is_initial_development());
+        assert!(!Version::new(1, 0, 0).is_initial_development());
     }
 
     #[test]
@@ -324,6 +3158,224 @@ mod tests {
         assert!(!v1.is_compatible(&v2));
     }
 
+    #[test]
+    fn is_not_compatible_with_beta_from_stable() {
+        let v1 = Version::new(1, 2, 0);
+        let v2 = Version::new(0, 9, 0);
+
+        assert!(!v1.is_compatible(&v2));
+    }
+
+    #[test]
+    fn is_not_compatible_with_stable_from_beta() {
+        let v1 = Version::new(0, 9, 0);
+        let v2 = Version::new(1, 2, 0);
+
+        assert!(!v1.is_compatible(&v2));
+    }
+
+    #[test]
+    fn in_range_boundaries() {
+        let low = Version::new(1, 2, 0);
+        let high = Version::new(1, 3, 0);
+
+        assert!(!Version::new(1, 1, 9).in_range(&low, &high));
+        assert!(Version::new(1, 2, 0).in_range(&low, &high));
+        assert!(Version::new(1, 2, 99).in_range(&low, &high));
+        assert!(!Version::new(1, 3, 0).in_range(&low, &high));
+    }
+
+    #[test]
+    fn in_range_inclusive_boundaries() {
+        let low = Version::new(1, 2, 0);
+        let high = Version::new(1, 3, 0);
+
+        assert!(!Version::new(1, 1, 9).in_range_inclusive(&low, &high));
+        assert!(Version::new(1, 2, 0).in_range_inclusive(&low, &high));
+        assert!(Version::new(1, 3, 0).in_range_inclusive(&low, &high));
+        assert!(!Version::new(1, 3, 1).in_range_inclusive(&low, &high));
+    }
+
+    #[test]
+    fn clamped_into_window() {
+        let min = Version::new(1, 2, 0);
+        let max = Version::new(1, 8, 0);
+
+        assert_eq!(Version::new(1, 1, 9).clamped(&min, &max), min);
+        assert_eq!(Version::new(1, 5, 3).clamped(&min, &max), "1.5.3");
+        assert_eq!(Version::new(1, 8, 1).clamped(&min, &max), max);
+    }
+
+    #[test]
+    fn in_caret_range() {
+        let base = Version::new(8, 9, 1);
+
+        assert!(Version::in_caret_range(&base, &Version::new(8, 10, 5)));
+        assert!(Version::in_caret_range(&base, &Version::new(8, 9, 2)));
+        assert!(!Version::in_caret_range(&base, &Version::new(9, 5, 1)));
+        assert!(!Version::in_caret_range(&base, &Version::new(8, 9, 0)));
+    }
+
+    #[test]
+    fn in_caret_range_on_beta() {
+        let base = Version::new(0, 10, 5);
+
+        assert!(Version::in_caret_range(&base, &Version::new(0, 10, 6)));
+        assert!(!Version::in_caret_range(&base, &Version::new(0, 11, 0)));
+    }
+
+    #[test]
+    fn in_tilde_range() {
+        let base = Version::new(30, 11, 20);
+
+        assert!(Version::in_tilde_range(&base, &Version::new(30, 11, 21)));
+        assert!(!Version::in_tilde_range(&base, &Version::new(30, 12, 0)));
+        assert!(!Version::in_tilde_range(&base, &Version::new(31, 11, 20)));
+    }
+
+    #[test]
+    fn compatibility_key() {
+        let key = Version::new(1, 2, 0).compatibility_key();
+        assert_eq!(Version::new(1, 9, 9).compatibility_key(), key);
+        assert_ne!(Version::new(2, 0, 0).compatibility_key(), key);
+    }
+
+    #[test]
+    fn compatibility_key_on_beta() {
+        let key = Version::new(0, 2, 1).compatibility_key();
+        assert_eq!(Version::new(0, 2, 9).compatibility_key(), key);
+        assert_ne!(Version::new(0, 3, 0).compatibility_key(), key);
+    }
+
+    #[test]
+    fn next_breaking() {
+        assert!(Version::new(1, 2, 3).next_breaking() == Some(Version::new(2, 0, 0)));
+        assert!(Version::new(0, 2, 3).next_breaking() == Some(Version::new(0, 3, 0)));
+        assert!(Version::new(0, 0, 3).next_breaking() == Some(Version::new(0, 1, 0)));
+    }
+
+    #[test]
+    fn next_breaking_agrees_with_caret() {
+        for v in [
+            Version::new(0, 0, 3),
+            Version::new(0, 2, 3),
+            Version::new(1, 2, 3),
+        ] {
+            let upper = v
+                .next_breaking()
+                .expect("the part to increment should not overflow");
+
+            assert!(!upper.is_compatible(&v));
+            assert!(!crate::Range::caret(&v).contains(&upper));
+        }
+    }
+
+    #[test]
+    fn next_breaking_overflow() {
+        assert_eq!(Version::new(u32::MAX, 0, 0).next_breaking(), None);
+        assert_eq!(Version::new(0, u32::MAX, 0).next_breaking(), None);
+    }
+
+    #[test]
+    fn eq_minor() {
+        let v = Version::new(1, 2, 3);
+
+        assert!(v.eq_minor(&Version::new(1, 2, 9)));
+        assert!(v.eq_minor(&Version::new(1, 2, 0)));
+        assert!(!v.eq_minor(&Version::new(1, 3, 0)));
+        assert!(!v.eq_minor(&Version::new(2, 2, 3)));
+    }
+
+    #[test]
+    fn eq_major() {
+        let v = Version::new(1, 2, 3);
+
+        assert!(v.eq_major(&Version::new(1, 3, 0)));
+        assert!(!v.eq_major(&Version::new(0, 2, 3)));
+    }
+
+    #[test]
+    fn earliest_compatible() {
+        let v = Version::new(1, 5, 3);
+        assert!(v.earliest_compatible() == Version::new(1, 0, 0));
+    }
+
+    #[test]
+    fn earliest_compatible_on_beta() {
+        let v = Version::new(0, 5, 3);
+        assert!(v.earliest_compatible() == Version::new(0, 5, 0));
+    }
+
+    #[test]
+    fn highest_compatible_skips_breaking_candidates() {
+        let v = Version::new(1, 2, 0);
+        let candidates = [
+            Version::new(1, 1, 0),
+            Version::new(1, 8, 4),
+            Version::new(2, 1, 0),
+            Version::new(1, 9, 3),
+            Version::new(2, 0, 0),
+        ];
+
+        assert!(v.highest_compatible(&candidates) == Some(&candidates[3]));
+    }
+
+    #[test]
+    fn highest_compatible_without_candidates() {
+        let v = Version::new(1, 2, 0);
+        let candidates = [Version::new(1, 1, 0), Version::new(2, 0, 0)];
+
+        assert!(v.highest_compatible(&candidates).is_none());
+    }
+
+    #[test]
+    fn closest_differs_in_patch() {
+        let v = Version::new(1, 2, 3);
+        let candidates = [
+            Version::new(2, 2, 3),
+            Version::new(1, 3, 3),
+            Version::new(1, 2, 7),
+            Version::new(1, 2, 0),
+        ];
+
+        assert!(v.closest(&candidates) == Some(&candidates[3]));
+    }
+
+    #[test]
+    fn closest_prefers_lower_parts() {
+        let v = Version::new(1, 2, 3);
+        let candidates = [Version::new(0, 2, 3), Version::new(1, 900, 0)];
+
+        assert!(v.closest(&candidates) == Some(&candidates[1]));
+        assert!(v.closest(&[]).is_none());
+    }
+
+    #[test]
+    fn is_compatible_at_u32_max() {
+        assert!(Version::MAX.is_compatible(&Version::new(u32::MAX, 0, 0)));
+        assert!(Version::new(1, u32::MAX, 9).is_featureless(&Version::new(1, u32::MAX, 0)));
+    }
+
+    #[test]
+    fn compatible_either_way() {
+        let older = Version::new(1, 2, 9);
+        let newer = Version::new(1, 5, 7);
+
+        assert!(newer.is_compatible(&older));
+        assert!(!older.is_compatible(&newer));
+        assert!(older.compatible_either_way(&newer));
+        assert!(newer.compatible_either_way(&older));
+    }
+
+    #[test]
+    fn incompatible_either_way() {
+        let v1 = Version::new(1, 5, 7);
+        let v2 = Version::new(2, 0, 0);
+
+        assert!(!v1.compatible_either_way(&v2));
+        assert!(!Version::new(0, 2, 0).compatible_either_way(&Version::new(0, 3, 0)));
+    }
+
     #[test]
     fn is_not_featureless_with_major_bump() {
         let v1 = Version::new(31, 9, 5);